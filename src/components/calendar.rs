@@ -3,7 +3,10 @@
 //! The goal of the calendar module of `boa_temporal` is to provide
 //! Temporal compatible calendar implementations.
 
-use std::str::FromStr;
+use core::{
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use crate::{
     components::{
@@ -29,7 +32,6 @@ use icu_calendar::{
     persian::Persian,
     roc::Roc,
     types::{DayOfMonth, DayOfYearInfo, Era, FormattableMonth, FormattableYear, MonthCode},
-    week::{RelativeUnit, WeekCalculator},
     AnyCalendar, AnyCalendarKind, Calendar as IcuCalendar, DateDuration as IcuDateDuration,
     DateDurationUnit as IcuDateDurationUnit, Gregorian, Iso, Ref,
 };
@@ -62,6 +64,13 @@ impl PartialEq for Calendar {
 
 impl Eq for Calendar {}
 
+// NOTE: Hashes the identifier to stay consistent with `PartialEq`.
+impl Hash for Calendar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identifier().hash(state);
+    }
+}
+
 impl IcuCalendar for Calendar {
     type DateInner = AnyDateInner;
 
@@ -187,14 +196,26 @@ impl Calendar {
 impl FromStr for Calendar {
     type Err = TemporalError;
 
+    /// Parses a calendar identifier, ASCII case-insensitively.
+    ///
+    /// Deprecated aliases such as `gregorian`, `islamicc`, and `ethiopic-amete-alem` are
+    /// normalized to their canonical identifier.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // NOTE(nekesss): Catch the iso identifier here, as `iso8601` is not a valid ID below.
-        if s == "iso8601" {
-            return Ok(Self::default());
-        }
+        let lowercase = s.to_ascii_lowercase();
+        let id = match lowercase.as_str() {
+            // NOTE(nekesss): Catch the iso identifier here, as `iso8601` is not a valid ID below.
+            "iso8601" => return Ok(Self::default()),
+            // NOTE: `iso` is only an ICU4X identifier, so it is rejected below like any unknown ID.
+            "iso" => "",
+            "gregorian" => "gregory",
+            "islamicc" => "islamic-civil",
+            "ethiopic-amete-alem" => "ethioaa",
+            id => id,
+        };
 
-        let Some(cal) = AnyCalendarKind::get_for_bcp47_string(s) else {
-            return Err(TemporalError::range().with_message("Not a builtin calendar."));
+        let Some(cal) = AnyCalendarKind::get_for_bcp47_string(id) else {
+            return Err(TemporalError::range()
+                .with_message(format!("Not a builtin calendar identifier: \"{s}\"")));
         };
 
         Ok(Calendar::new(cal))
@@ -276,6 +297,22 @@ impl Calendar {
         &self,
         partial: &PartialDate,
         overflow: ArithmeticOverflow,
+    ) -> TemporalResult<PlainDate> {
+        let date = self.date_from_resolved_partial(partial, overflow)?;
+        // A `year` that conflicts with the provided `era` and `eraYear` is rejected.
+        if let (Some(year), Some(_), Some(_)) = (partial.year, partial.era, partial.era_year) {
+            if date.year()? != year {
+                return Err(TemporalError::range()
+                    .with_message("year does not match the provided era and eraYear."));
+            }
+        }
+        Ok(date)
+    }
+
+    fn date_from_resolved_partial(
+        &self,
+        partial: &PartialDate,
+        overflow: ArithmeticOverflow,
     ) -> TemporalResult<PlainDate> {
         let fields = self.resolve_partial_date_fields(partial, overflow)?;
 
@@ -291,7 +328,7 @@ impl Calendar {
         }
 
         let calendar_date = self.0.date_from_codes(
-            Era(fields.era_year.era.0),
+            Era(era::to_icu_era_code(fields.era_year.era.0)),
             fields.era_year.year,
             MonthCode(fields.month_code.0),
             fields.day as u8, // TODO: FIX
@@ -491,15 +528,8 @@ impl Calendar {
     /// `CalendarWeekOfYear`
     pub fn week_of_year(&self, date_like: &CalendarDateLike) -> TemporalResult<Option<u16>> {
         if self.is_iso() {
-            let date = date_like.as_iso_date().as_icu4x()?;
-
-            let week_calculator = WeekCalculator::default();
-
-            let week_of = date
-                .week_of_year(&week_calculator)
-                .map_err(|err| TemporalError::range().with_message(err.to_string()))?;
-
-            return Ok(Some(week_of.week));
+            let (week, _) = date_like.as_iso_date().iso_week_of_year();
+            return Ok(Some(week.into()));
         }
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
@@ -507,19 +537,8 @@ impl Calendar {
     /// `CalendarYearOfWeek`
     pub fn year_of_week(&self, date_like: &CalendarDateLike) -> TemporalResult<Option<i32>> {
         if self.is_iso() {
-            let date = date_like.as_iso_date().as_icu4x()?;
-
-            let week_calculator = WeekCalculator::default();
-
-            let week_of = date
-                .week_of_year(&week_calculator)
-                .map_err(|err| TemporalError::range().with_message(err.to_string()))?;
-
-            return match week_of.unit {
-                RelativeUnit::Previous => Ok(Some(date.year().number - 1)),
-                RelativeUnit::Current => Ok(Some(date.year().number)),
-                RelativeUnit::Next => Ok(Some(date.year().number + 1)),
-            };
+            let (_, year) = date_like.as_iso_date().iso_week_of_year();
+            return Ok(Some(year));
         }
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
@@ -573,7 +592,7 @@ impl Calendar {
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
 
-    /// Returns the identifier of this calendar slot.
+    /// Returns the canonical identifier of this calendar slot, e.g. `iso8601` or `gregory`.
     pub fn identifier(&self) -> &'static str {
         if self.is_iso() {
             return "iso8601";
@@ -744,10 +763,45 @@ impl From<PlainYearMonth> for Calendar {
 
 #[cfg(test)]
 mod tests {
-    use crate::{components::PlainDate, iso::IsoDate, options::TemporalUnit};
+    use crate::{components::PlainDate, error::ErrorKind, iso::IsoDate, options::TemporalUnit};
+
+    use core::str::FromStr;
 
     use super::Calendar;
 
+    #[test]
+    fn calendar_from_str_identifiers() {
+        let cases = [
+            ("iso8601", "iso8601"),
+            ("ISO8601", "iso8601"),
+            ("gregory", "gregory"),
+            ("Gregory", "gregory"),
+            ("gregorian", "gregory"),
+            ("japanese", "japanese"),
+            ("islamic-civil", "islamic-civil"),
+            ("islamicc", "islamic-civil"),
+            ("ethioaa", "ethioaa"),
+            ("ethiopic-amete-alem", "ethioaa"),
+            ("buddhist", "buddhist"),
+        ];
+        for (id, canonical) in cases {
+            let calendar = Calendar::from_str(id).unwrap();
+            assert_eq!(calendar.identifier(), canonical, "{id}");
+            assert_eq!(Calendar::from_str(canonical).unwrap(), calendar);
+        }
+
+        for id in [
+            "",
+            "iso",
+            "gregory-calendar",
+            "not-a-calendar",
+            "islamic civil",
+        ] {
+            let err = Calendar::from_str(id).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range, "{id}");
+        }
+    }
+
     #[test]
     fn date_until_largest_year() {
         // tests format: (Date one, PlainDate two, Duration result)
@@ -9,7 +9,7 @@
 
 // TODO (0.1.0): Feature flag certain eras as experimental
 
-use std::ops::RangeInclusive;
+use core::ops::RangeInclusive;
 
 use tinystr::{tinystr, TinyAsciiStr};
 
@@ -146,3 +146,14 @@ pub(crate) const TAISHO_ERA: EraInfo = valid_era!("showa", 1..=45);
 pub(crate) const PERSIAN_ERA: EraInfo = valid_era!("persian", i32::MIN..=i32::MAX);
 pub(crate) const ROC_ERA: EraInfo = valid_era!("roc", 1..=i32::MAX);
 pub(crate) const ROC_INVERSE_ERA: EraInfo = valid_era!("roc-inverse", 1..=i32::MAX);
+
+/// Maps a resolved era name to the era code expected by ICU4X's `date_from_codes`.
+pub(crate) fn to_icu_era_code(era: TinyAsciiStr<16>) -> TinyAsciiStr<16> {
+    if era == GREGORY_ERA.name {
+        tinystr!(16, "ce")
+    } else if era == GREGORY_INVERSE_ERA.name {
+        tinystr!(16, "bce")
+    } else {
+        era
+    }
+}
@@ -102,7 +102,9 @@ impl EraYear {
                     year,
                 })
             }
-            (None, Some(era), Some(era_year)) => {
+            // NOTE: A `year` provided alongside `era` and `eraYear` is checked against the
+            // resulting date by the caller.
+            (_, Some(era), Some(era_year)) => {
                 let Some(era_info) = calendar.get_era_info(&era) else {
                     return Err(TemporalError::range().with_message("Invalid era provided."));
                 };
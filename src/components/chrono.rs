@@ -0,0 +1,151 @@
+//! Conversions between Temporal components and `chrono` types.
+//!
+//! NOTE: `chrono` represents a leap second with a nanosecond value of 1_000_000_000 or
+//! greater. Temporal does not represent leap seconds, so these values are rejected.
+
+use ::chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, Timelike, Utc};
+
+use crate::{
+    components::{calendar::Calendar, Instant, PlainDate, PlainTime},
+    TemporalError, NS_PER_SECOND,
+};
+
+// ==== Instant conversions ====
+
+impl TryFrom<DateTime<Utc>> for Instant {
+    type Error = TemporalError;
+
+    fn try_from(value: DateTime<Utc>) -> Result<Self, Self::Error> {
+        let subsecond_nanos = value.timestamp_subsec_nanos();
+        if subsecond_nanos >= NS_PER_SECOND {
+            return Err(TemporalError::leap_second());
+        }
+        let epoch_nanos =
+            i128::from(value.timestamp()) * i128::from(NS_PER_SECOND) + i128::from(subsecond_nanos);
+        Self::new(epoch_nanos)
+    }
+}
+
+impl TryFrom<Instant> for DateTime<Utc> {
+    type Error = TemporalError;
+
+    fn try_from(value: Instant) -> Result<Self, Self::Error> {
+        let (seconds, subsecond_nanos) = (
+            value.epoch_nanos.div_euclid(i128::from(NS_PER_SECOND)),
+            value.epoch_nanos.rem_euclid(i128::from(NS_PER_SECOND)),
+        );
+        // NOTE: A valid `Instant` is always within an `i64` range of seconds.
+        DateTime::from_timestamp(seconds as i64, subsecond_nanos as u32).ok_or(
+            TemporalError::range().with_message("Instant is not within chrono's DateTime range."),
+        )
+    }
+}
+
+// ==== PlainDate conversions ====
+
+impl TryFrom<NaiveDate> for PlainDate {
+    type Error = TemporalError;
+
+    fn try_from(value: NaiveDate) -> Result<Self, Self::Error> {
+        PlainDate::try_new(
+            value.year(),
+            value.month() as i32,
+            value.day() as i32,
+            Calendar::default(),
+        )
+    }
+}
+
+impl TryFrom<PlainDate> for NaiveDate {
+    type Error = TemporalError;
+
+    fn try_from(value: PlainDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(
+            value.iso_year(),
+            value.iso_month().into(),
+            value.iso_day().into(),
+        )
+        .ok_or(TemporalError::range().with_message("PlainDate is not within chrono's date range."))
+    }
+}
+
+// ==== PlainTime conversions ====
+
+impl TryFrom<NaiveTime> for PlainTime {
+    type Error = TemporalError;
+
+    fn try_from(value: NaiveTime) -> Result<Self, Self::Error> {
+        let nanosecond = value.nanosecond();
+        if nanosecond >= NS_PER_SECOND {
+            return Err(TemporalError::leap_second());
+        }
+        PlainTime::try_new(
+            value.hour() as i32,
+            value.minute() as i32,
+            value.second() as i32,
+            (nanosecond / 1_000_000) as i32,
+            (nanosecond / 1_000 % 1_000) as i32,
+            (nanosecond % 1_000) as i32,
+        )
+    }
+}
+
+impl From<PlainTime> for NaiveTime {
+    fn from(value: PlainTime) -> Self {
+        // NOTE: A `PlainTime` is always less than a day, so adding its nanoseconds to midnight
+        // never wraps around.
+        let nanoseconds = value.to_nanoseconds_since_midnight() as i64;
+        NaiveTime::MIN
+            .overflowing_add_signed(TimeDelta::nanoseconds(nanoseconds))
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+    use crate::{
+        components::{Instant, PlainDate, PlainTime},
+        NS_MAX_INSTANT,
+    };
+
+    #[test]
+    fn instant_round_trip() {
+        let date_time = DateTime::<Utc>::from_timestamp(-1, 123_456_789).unwrap();
+        let instant = Instant::try_from(date_time).unwrap();
+        assert_eq!(instant.epoch_nanos, -876_543_211);
+        assert_eq!(DateTime::<Utc>::try_from(instant).unwrap(), date_time);
+
+        let leap_second = DateTime::<Utc>::from_timestamp(59, 1_500_000_000).unwrap();
+        assert!(Instant::try_from(leap_second).is_err());
+
+        let max = Instant::new(NS_MAX_INSTANT).unwrap();
+        assert!(DateTime::<Utc>::try_from(max).is_err());
+    }
+
+    #[test]
+    fn date_round_trip() {
+        let naive = NaiveDate::from_ymd_opt(1969, 12, 31).unwrap();
+        let date = PlainDate::try_from(naive).unwrap();
+        assert_eq!(
+            (date.iso_year(), date.iso_month(), date.iso_day()),
+            (1969, 12, 31)
+        );
+        assert_eq!(NaiveDate::try_from(date).unwrap(), naive);
+    }
+
+    #[test]
+    fn time_round_trip() {
+        let naive = NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap();
+        let time = PlainTime::try_from(naive).unwrap();
+        assert_eq!(
+            (time.millisecond(), time.microsecond(), time.nanosecond()),
+            (123, 456, 789)
+        );
+        assert_eq!(NaiveTime::from(time), naive);
+
+        let leap_second = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap();
+        assert!(PlainTime::try_from(leap_second).is_err());
+    }
+}
@@ -13,11 +13,12 @@ use crate::{
         ArithmeticOverflow, DifferenceOperation, DifferenceSettings, ResolvedRoundingOptions,
         TemporalUnit,
     },
-    parsers::parse_date_time,
+    parsers::parse_plain_date_time,
     primitive::FiniteF64,
     Sign, TemporalError, TemporalResult, TemporalUnwrap,
 };
-use std::str::FromStr;
+use core::str::FromStr;
+use num_traits::AsPrimitive;
 
 use super::{
     calendar::{ascii_four_to_integer, month_to_month_code},
@@ -86,19 +87,32 @@ impl PartialDate {
 macro_rules! impl_with_fallback_method {
     ($method_name:ident, $component_type:ty) => {
         pub(crate) fn $method_name(&self, fallback: &$component_type) -> TemporalResult<Self> {
-            let era = if let Some(era) = self.era {
-                Some(era)
+            let fallback_era = || -> TemporalResult<Option<TinyAsciiStr<19>>> {
+                fallback
+                    .era()?
+                    .map(|e| {
+                        TinyAsciiStr::<19>::from_bytes(e.as_bytes())
+                            .map_err(|e| TemporalError::general(format!("{e}")))
+                    })
+                    .transpose()
+            };
+
+            // NOTE: A provided `year` replaces the fallback's `era` and `eraYear`, and a provided
+            // `era` or `eraYear` replaces the fallback's `year`.
+            let (year, era, era_year) = if self.era.is_some() || self.era_year.is_some() {
+                let era = self.era.map_or_else(fallback_era, |era| Ok(Some(era)))?;
+                let era_year = self
+                    .era_year
+                    .map_or_else(|| fallback.era_year(), |ey| Ok(Some(ey)))?;
+                (self.year, era, era_year)
+            } else if self.year.is_some() {
+                (self.year, None, None)
             } else {
-                let era = fallback.era()?;
-                era.map(|e| {
-                    TinyAsciiStr::<19>::from_bytes(e.as_bytes())
-                        .map_err(|e| TemporalError::general(format!("{e}")))
-                })
-                .transpose()?
+                match fallback_era()? {
+                    Some(era) => (None, Some(era), fallback.era_year()?),
+                    None => (Some(fallback.year()?), None, None),
+                }
             };
-            let era_year = self
-                .era_year
-                .map_or_else(|| fallback.era_year(), |ey| Ok(Some(ey)))?;
 
             let (month, month_code) = match (self.month, self.month_code) {
                 (Some(month), Some(mc)) => (Some(month), Some(mc)),
@@ -111,7 +125,7 @@ macro_rules! impl_with_fallback_method {
             };
 
             Ok(Self {
-                year: Some(self.year.unwrap_or(fallback.year()?)),
+                year,
                 month,
                 month_code,
                 day: Some(self.day.unwrap_or(fallback.day()?.into())),
@@ -122,22 +136,111 @@ macro_rules! impl_with_fallback_method {
     };
 }
 
+/// A week number paired with its week-numbering year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekInfo {
+    /// The week of the year, from 1 to 53.
+    pub week: u8,
+    /// The year the week belongs to, which may differ from the calendar year near year boundaries.
+    pub year: i32,
+}
+
+/// A day of the week, numbered from Monday (1) to Sunday (7) as in ISO 8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Weekday {
+    /// Monday
+    Monday = 1,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the ISO 8601 number of this `Weekday`, from 1 (Monday) to 7 (Sunday).
+    #[inline]
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the `Weekday` for an ISO 8601 number from 1 (Monday) to 7 (Sunday), or `None` if
+    /// the number is out of range.
+    #[inline]
+    #[must_use]
+    pub const fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::Monday),
+            2 => Some(Self::Tuesday),
+            3 => Some(Self::Wednesday),
+            4 => Some(Self::Thursday),
+            5 => Some(Self::Friday),
+            6 => Some(Self::Saturday),
+            7 => Some(Self::Sunday),
+            _ => None,
+        }
+    }
+
+    /// Returns the following `Weekday`, wrapping from Sunday to Monday.
+    #[inline]
+    #[must_use]
+    pub const fn succ(self) -> Self {
+        match self {
+            Self::Monday => Self::Tuesday,
+            Self::Tuesday => Self::Wednesday,
+            Self::Wednesday => Self::Thursday,
+            Self::Thursday => Self::Friday,
+            Self::Friday => Self::Saturday,
+            Self::Saturday => Self::Sunday,
+            Self::Sunday => Self::Monday,
+        }
+    }
+
+    /// Returns the preceding `Weekday`, wrapping from Monday to Sunday.
+    #[inline]
+    #[must_use]
+    pub const fn pred(self) -> Self {
+        match self {
+            Self::Monday => Self::Sunday,
+            Self::Tuesday => Self::Monday,
+            Self::Wednesday => Self::Tuesday,
+            Self::Thursday => Self::Wednesday,
+            Self::Friday => Self::Thursday,
+            Self::Saturday => Self::Friday,
+            Self::Sunday => Self::Saturday,
+        }
+    }
+}
+
 /// The native Rust implementation of `Temporal.PlainDate`.
+///
+/// Equality (`==`) mirrors `Temporal.PlainDate.prototype.equals`, so dates with different
+/// calendars are never equal. Ordering mirrors `Temporal.PlainDate.compare` and only considers
+/// the ISO date, so the same day in two calendars compares as `Ordering::Equal`.
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct PlainDate {
     pub(crate) iso: IsoDate,
     calendar: Calendar,
 }
 
 impl Ord for PlainDate {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.iso.cmp(&other.iso)
     }
 }
 
 impl PartialOrd for PlainDate {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -187,19 +290,46 @@ impl PlainDate {
         )?;
 
         // 7. Let result be ? AddISODate(plainDate.[[ISOYear]], plainDate.[[ISOMonth]], plainDate.[[ISODay]], 0, 0, 0, days, overflow).
-        let result = self.iso.add_date_duration(
-            &DateDuration::new(
-                FiniteF64::default(),
-                FiniteF64::default(),
-                FiniteF64::default(),
-                days,
-            )?,
-            overflow,
-        )?;
+        // NOTE: With only days to add, `overflow` has no effect and the days are added to the
+        // epoch days directly rather than balancing the ISO date.
+        let result = self.iso.add_days(days.as_::<i64>())?;
+        if !IsoDateTime::new_unchecked(result, IsoTime::noon()).is_within_limits() {
+            return Err(
+                TemporalError::range().with_message("Resulting date is not within a valid range.")
+            );
+        }
 
         Ok(Self::new_unchecked(result, self.calendar().clone()))
     }
 
+    /// Returns the total number of days represented by a `DateDuration` relative to this date.
+    ///
+    /// Temporal Equivalent: `DateDurationDays ( dateDuration, plainRelativeTo )`
+    pub(crate) fn date_duration_days(&self, duration: &DateDuration) -> TemporalResult<i64> {
+        // 1. Let yearsMonthsWeeksDuration be ! AdjustDateDurationRecord(dateDuration, 0).
+        let ymw_duration = DateDuration::new(
+            duration.years,
+            duration.months,
+            duration.weeks,
+            FiniteF64::default(),
+        )?;
+        // 2. If DateDurationSign(yearsMonthsWeeksDuration) = 0, return dateDuration.[[Days]].
+        if ymw_duration.sign() == Sign::Zero {
+            return Ok(duration.days.as_());
+        }
+        // 3. Let later be ? CalendarDateAdd(plainRelativeTo.[[Calendar]], plainRelativeTo.[[ISODate]], yearsMonthsWeeksDuration, constrain).
+        let later = self.add_date(
+            &Duration::from(ymw_duration),
+            Some(ArithmeticOverflow::Constrain),
+        )?;
+        // 4. Let epochDays1 be ISODateToEpochDays(plainRelativeTo.[[ISODate]].[[Year]], plainRelativeTo.[[ISODate]].[[Month]] - 1, plainRelativeTo.[[ISODate]].[[Day]]).
+        // 5. Let epochDays2 be ISODateToEpochDays(later.[[Year]], later.[[Month]] - 1, later.[[Day]]).
+        // 6. Let yearsMonthsWeeksInDays be epochDays2 - epochDays1.
+        let ymw_in_days = i64::from(self.days_until(&later));
+        // 7. Return dateDuration.[[Days]] + yearsMonthsWeeksInDays.
+        Ok(duration.days.as_::<i64>() + ymw_in_days)
+    }
+
     /// Returns a duration representing the difference between the dates one and two.
     ///
     /// Temporal Equivalent: 3.5.6 `DifferenceDate ( calendar, one, two, options )`
@@ -325,6 +455,30 @@ impl PlainDate {
         Ok(Self::new_unchecked(iso, calendar))
     }
 
+    /// Creates a new `PlainDate` from an era, era year, month, and day with the specified overflow.
+    ///
+    /// The era is validated against the provided calendar, e.g. `("bce", 44, 3, 15)` in the
+    /// Gregorian calendar is March 15th, 44 BCE.
+    pub fn new_with_era(
+        era: &str,
+        era_year: i32,
+        month: i32,
+        day: i32,
+        calendar: Calendar,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let era = TinyAsciiStr::<19>::from_str(era)
+            .map_err(|_| TemporalError::range().with_message("Invalid era provided."))?;
+        let partial = PartialDate {
+            month: Some(month),
+            day: Some(day),
+            era: Some(era),
+            era_year: Some(era_year),
+            ..Default::default()
+        };
+        calendar.date_from_partial(&partial, overflow)
+    }
+
     /// Creates a date time with values from a `PartialDate`.
     pub fn with(
         &self,
@@ -346,13 +500,13 @@ impl PlainDate {
     }
 
     /// Creates a new `Date` from the current `Date` and the provided calendar.
-    pub fn with_calendar(&self, calendar: Calendar) -> TemporalResult<Self> {
-        Self::try_new(
-            self.iso_year(),
-            self.iso_month().into(),
-            self.iso_day().into(),
-            calendar,
-        )
+    ///
+    /// The ISO date is preserved exactly, while the calendar fields such as the year, month, day,
+    /// and era are reported in the new calendar.
+    #[inline]
+    #[must_use]
+    pub fn with_calendar(&self, calendar: Calendar) -> Self {
+        Self::new_unchecked(self.iso, calendar)
     }
 
     #[inline]
@@ -391,6 +545,66 @@ impl PlainDate {
         self.iso.is_valid()
     }
 
+    /// Returns the ISO 8601 week of year and the year that week belongs to.
+    ///
+    /// Both values are computed together, so a date like 2019-12-30 is always paired as
+    /// week 1 of 2020.
+    #[must_use]
+    pub fn iso_week_of_year(&self) -> WeekInfo {
+        let (week, year) = self.iso.iso_week_of_year();
+        WeekInfo { week, year }
+    }
+
+    /// Returns the week of the ISO date for weeks starting on `first_day`, where week 1 is the
+    /// first week with at least `min_days_in_first_week` days in the year.
+    ///
+    /// ISO 8601 weeks start on Monday with a minimum of 4 days, while US-style weeks start on
+    /// Sunday with a minimum of 1 day, i.e. week 1 is the week containing January 1st.
+    pub fn week_of_year_with(
+        &self,
+        first_day: Weekday,
+        min_days_in_first_week: u8,
+    ) -> TemporalResult<WeekInfo> {
+        if !(1..=7).contains(&min_days_in_first_week) {
+            return Err(TemporalError::range()
+                .with_message("min_days_in_first_week must be between 1 and 7."));
+        }
+        let (week, year) = self
+            .iso
+            .week_of_year_with(first_day.number(), min_days_in_first_week);
+        Ok(WeekInfo { week, year })
+    }
+
+    /// Compares two `PlainDate`s by their ISO date, ignoring their calendars.
+    ///
+    /// Temporal equivalent: `Temporal.PlainDate.compare`
+    #[inline]
+    #[must_use]
+    pub fn compare(one: &Self, two: &Self) -> core::cmp::Ordering {
+        one.iso.cmp(&two.iso)
+    }
+
+    /// Creates a new ISO `PlainDate` from the number of days since the Unix epoch, 1970-01-01.
+    pub fn from_epoch_days(epoch_days: i64) -> TemporalResult<Self> {
+        let epoch_days = i32::try_from(epoch_days).map_err(|_| {
+            TemporalError::range().with_message("Date is not within ISO date time limits.")
+        })?;
+        let iso = IsoDate::from_epoch_days(epoch_days);
+        Self::try_new(
+            iso.year,
+            iso.month.into(),
+            iso.day.into(),
+            Calendar::default(),
+        )
+    }
+
+    /// Returns the number of days since the Unix epoch, 1970-01-01, for this `PlainDate`.
+    #[inline]
+    #[must_use]
+    pub fn to_epoch_days(&self) -> i64 {
+        self.iso.to_epoch_days().into()
+    }
+
     /// `DaysUntil`
     ///
     /// Calculates the epoch days between two `Date`s
@@ -402,6 +616,10 @@ impl PlainDate {
 
     #[inline]
     /// Adds a `Duration` to the current `Date`
+    ///
+    /// Years and months are added first, and the day is then constrained to the resulting month,
+    /// or rejected with `ArithmeticOverflow::Reject`. Weeks and days are added last as a plain
+    /// count of days, so `2024-01-31` plus `P1M1W` is `2024-02-29` plus 7 days, `2024-03-07`.
     pub fn add(
         &self,
         duration: &Duration,
@@ -420,6 +638,26 @@ impl PlainDate {
         self.add_date(&duration.negated(), overflow)
     }
 
+    /// Adds a `DateDuration` to the current `Date`.
+    #[inline]
+    pub fn add_date_duration(
+        &self,
+        duration: &DateDuration,
+        overflow: Option<ArithmeticOverflow>,
+    ) -> TemporalResult<Self> {
+        self.add_date(&Duration::from(*duration), overflow)
+    }
+
+    /// Subtracts a `DateDuration` from the current `Date`.
+    #[inline]
+    pub fn subtract_date_duration(
+        &self,
+        duration: &DateDuration,
+        overflow: Option<ArithmeticOverflow>,
+    ) -> TemporalResult<Self> {
+        self.add_date(&Duration::from(duration.negated()), overflow)
+    }
+
     #[inline]
     /// Returns a `Duration` representing the time from this `Date` until the other `Date`.
     pub fn until(&self, other: &Self, settings: DifferenceSettings) -> TemporalResult<Duration> {
@@ -456,9 +694,13 @@ impl PlainDate {
         self.calendar.day(&CalendarDateLike::Date(self))
     }
 
-    /// Returns the calendar day of week value.
-    pub fn day_of_week(&self) -> TemporalResult<u16> {
-        self.calendar.day_of_week(&CalendarDateLike::Date(self))
+    /// Returns the calendar day of week value as a `Weekday`.
+    pub fn day_of_week(&self) -> TemporalResult<Weekday> {
+        let number = self.calendar.day_of_week(&CalendarDateLike::Date(self))?;
+        u8::try_from(number)
+            .ok()
+            .and_then(Weekday::from_number)
+            .temporal_unwrap()
     }
 
     /// Returns the calendar day of year value.
@@ -525,23 +767,51 @@ impl PlainDate {
         Ok(PlainDateTime::new_unchecked(iso, self.get_calendar()))
     }
 
-    /// Converts the current `Date<C>` into a `PlainYearMonth`
+    /// Converts the current `Date` into a `PlainYearMonth` with the same calendar.
+    ///
+    /// Equivalent to `Temporal.PlainDate.prototype.toPlainYearMonth`.
     #[inline]
-    pub fn to_year_month(&self) -> TemporalResult<PlainYearMonth> {
+    pub fn to_plain_year_month(&self) -> TemporalResult<PlainYearMonth> {
+        if self.calendar.is_iso() {
+            // NOTE: An ISO `PlainYearMonth` uses the first of the month as its reference day.
+            let iso = IsoDate::new_unchecked(self.iso.year, self.iso.month, 1);
+            return Ok(PlainYearMonth::new_unchecked(iso, self.calendar.clone()));
+        }
         self.get_calendar().year_month_from_partial(
             &PartialDate::default().with_fallback_date(self)?,
             ArithmeticOverflow::Constrain,
         )
     }
 
-    /// Converts the current `Date<C>` into a `PlainMonthDay`
+    /// Converts the current `Date` into a `PlainMonthDay` with the same calendar.
+    ///
+    /// Equivalent to `Temporal.PlainDate.prototype.toPlainMonthDay`. A leap day is preserved,
+    /// as an ISO `PlainMonthDay` uses the leap year 1972 as its reference year.
     #[inline]
-    pub fn to_month_day(&self) -> TemporalResult<PlainMonthDay> {
+    pub fn to_plain_month_day(&self) -> TemporalResult<PlainMonthDay> {
+        if self.calendar.is_iso() {
+            let iso = IsoDate::new_unchecked(1972, self.iso.month, self.iso.day);
+            return Ok(PlainMonthDay::new_unchecked(iso, self.calendar.clone()));
+        }
         self.get_calendar().month_day_from_partial(
             &PartialDate::default().with_fallback_date(self)?,
             ArithmeticOverflow::Constrain,
         )
     }
+
+    /// Converts the current `Date` into a `PlainYearMonth`.
+    #[inline]
+    #[deprecated(note = "use to_plain_year_month")]
+    pub fn to_year_month(&self) -> TemporalResult<PlainYearMonth> {
+        self.to_plain_year_month()
+    }
+
+    /// Converts the current `Date` into a `PlainMonthDay`.
+    #[inline]
+    #[deprecated(note = "use to_plain_month_day")]
+    pub fn to_month_day(&self) -> TemporalResult<PlainMonthDay> {
+        self.to_plain_month_day()
+    }
 }
 
 // ==== Trait impls ====
@@ -571,9 +841,13 @@ impl FromStr for PlainDate {
     type Err = TemporalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parse_record = parse_date_time(s)?;
+        let (parse_record, annotations) = parse_plain_date_time(s)?;
 
-        let calendar = parse_record.calendar.unwrap_or("iso8601");
+        // NOTE: A time zone annotation is ignored, and the parser has already rejected any
+        // unknown critical annotation.
+        let calendar = annotations
+            .calendar
+            .map_or("iso8601", |annotation| annotation.value);
 
         // Assertion: PlainDate must exist on a DateTime parse.
         let date = parse_record.date.temporal_unwrap()?;
@@ -589,6 +863,126 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn day_of_week_and_weekday_iteration() {
+        let date = PlainDate::from_str("2024-05-06").unwrap();
+        assert_eq!(date.day_of_week().unwrap(), Weekday::Monday);
+        assert_eq!(date.day_of_week().unwrap().number(), 1);
+        let sunday = PlainDate::from_str("2024-05-12").unwrap();
+        assert_eq!(sunday.day_of_week().unwrap(), Weekday::Sunday);
+
+        let mut weekday = Weekday::Monday;
+        for number in 1..=7 {
+            assert_eq!(Weekday::from_number(number), Some(weekday));
+            assert_eq!(weekday.number(), number);
+            assert_eq!(weekday.succ().pred(), weekday);
+            weekday = weekday.succ();
+        }
+        assert_eq!(weekday, Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(Weekday::from_number(0), None);
+        assert_eq!(Weekday::from_number(8), None);
+    }
+
+    #[test]
+    fn add_days_matches_calendar_date_add() {
+        let base = PlainDate::from_str("2024-01-20").unwrap();
+        for days in [-400, -31, -20, -1, 0, 1, 11, 12, 30, 40, 366, 1_000] {
+            let duration = Duration::from_str(&format!("P{}D", days.abs())).unwrap();
+            let duration = if days < 0 {
+                duration.negated()
+            } else {
+                duration
+            };
+            let general = base
+                .calendar()
+                .date_add(&base, &duration, ArithmeticOverflow::Constrain)
+                .unwrap();
+            assert_eq!(base.add(&duration, None).unwrap(), general, "{days}");
+        }
+
+        let result = base
+            .add(&Duration::from_str("P30D").unwrap(), None)
+            .unwrap();
+        assert_eq!(
+            (result.iso_year(), result.iso_month(), result.iso_day()),
+            (2024, 2, 19)
+        );
+        // Time units are balanced into days first.
+        let result = base
+            .add(&Duration::from_str("P1DT47H").unwrap(), None)
+            .unwrap();
+        assert_eq!(result.iso_day(), 22);
+
+        let max = PlainDate::from_str("+275760-09-13").unwrap();
+        assert!(max.add(&Duration::from_str("P1D").unwrap(), None).is_err());
+        let min = PlainDate::from_str("-271821-04-19").unwrap();
+        assert!(min.add(&Duration::from_str("-P1D").unwrap(), None).is_err());
+        assert!(base
+            .add(&Duration::from_str("P1000000000D").unwrap(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn add_months_before_weeks_and_days() {
+        let ymd = |date: PlainDate| (date.iso_year(), date.iso_month(), date.iso_day());
+        let add = |date: &str, duration: &str, overflow| {
+            PlainDate::from_str(date)
+                .unwrap()
+                .add(&Duration::from_str(duration).unwrap(), overflow)
+        };
+
+        // The month is added and constrained to 2024-02-29 before adding the week.
+        assert_eq!(ymd(add("2024-01-31", "P1M1W", None).unwrap()), (2024, 3, 7));
+        assert_eq!(ymd(add("2024-01-31", "P1M7D", None).unwrap()), (2024, 3, 7));
+        assert_eq!(ymd(add("2023-01-31", "P1M1W", None).unwrap()), (2023, 3, 7));
+        assert_eq!(
+            ymd(add("2024-03-31", "-P1M1W", None).unwrap()),
+            (2024, 2, 22)
+        );
+        assert_eq!(
+            ymd(add("2024-02-29", "P1Y2W", None).unwrap()),
+            (2025, 3, 14)
+        );
+        assert!(add("2024-01-31", "P1M1W", Some(ArithmeticOverflow::Reject)).is_err());
+
+        // Weeks alone are a plain count of 7 days.
+        assert_eq!(
+            add("2024-12-28", "P1W", None).unwrap(),
+            add("2024-12-28", "P7D", None).unwrap()
+        );
+        assert_eq!(ymd(add("2024-12-28", "P1W", None).unwrap()), (2025, 1, 4));
+        assert_eq!(ymd(add("2024-01-31", "P5W3D", None).unwrap()), (2024, 3, 9));
+    }
+
+    #[test]
+    fn add_and_subtract_date_duration() {
+        let base = PlainDate::from_str("2024-01-31").unwrap();
+        let duration = DateDuration::new(
+            FiniteF64(0.0),
+            FiniteF64(1.0),
+            FiniteF64(0.0),
+            FiniteF64(1.0),
+        )
+        .unwrap();
+
+        let result = base.add_date_duration(&duration, None).unwrap();
+        assert_eq!(result, base.add(&Duration::from(duration), None).unwrap());
+        assert_eq!(
+            (result.iso_year(), result.iso_month(), result.iso_day()),
+            (2024, 3, 1)
+        );
+        assert!(base
+            .add_date_duration(&duration, Some(ArithmeticOverflow::Reject))
+            .is_err());
+
+        let result = result.subtract_date_duration(&duration, None).unwrap();
+        assert_eq!(
+            (result.iso_year(), result.iso_month(), result.iso_day()),
+            (2024, 1, 31)
+        );
+    }
+
     #[test]
     fn simple_date_add() {
         let base = PlainDate::from_str("1976-11-18").unwrap();
@@ -707,6 +1101,38 @@ mod tests {
         assert_eq!(result.days(), 9719.0,);
     }
 
+    #[test]
+    fn epoch_days_conversions() {
+        let cases = [
+            ((1970, 1, 1), 0),
+            ((1969, 12, 31), -1),
+            ((1999, 12, 31), 10_956),
+            ((2000, 1, 1), 10_957),
+            ((2000, 2, 29), 11_016),
+            ((1900, 3, 1), -25_508),
+            // The proleptic Gregorian epoch.
+            ((1, 1, 1), -719_162),
+            ((0, 12, 31), -719_163),
+            ((-271_821, 4, 19), -100_000_001),
+            ((275_760, 9, 13), 100_000_000),
+        ];
+
+        for ((year, month, day), epoch_days) in cases {
+            let date = PlainDate::try_new(year, month, day, Calendar::default()).unwrap();
+            assert_eq!(date.to_epoch_days(), epoch_days);
+
+            let date = PlainDate::from_epoch_days(epoch_days).unwrap();
+            assert_eq!(
+                (date.iso_year(), date.iso_month(), date.iso_day()),
+                (year, month as u8, day as u8)
+            );
+        }
+
+        assert!(PlainDate::from_epoch_days(-100_000_002).is_err());
+        assert!(PlainDate::from_epoch_days(100_000_001).is_err());
+        assert!(PlainDate::from_epoch_days(i64::MAX).is_err());
+    }
+
     #[test]
     fn date_with_empty_error() {
         let base = PlainDate::new(1976, 11, 18, Calendar::default()).unwrap();
@@ -776,6 +1202,150 @@ mod tests {
         assert_eq!(with_day.day().unwrap(), 17);
     }
 
+    #[test]
+    fn date_with_overflow_and_eras() {
+        let base = PlainDate::new(2020, 1, 31, Calendar::default()).unwrap();
+        let partial = PartialDate {
+            month: Some(2),
+            ..Default::default()
+        };
+        let constrained = base.with(partial, None).unwrap();
+        assert_eq!(
+            (constrained.month().unwrap(), constrained.day().unwrap()),
+            (2, 29)
+        );
+        assert!(base
+            .with(partial, Some(ArithmeticOverflow::Reject))
+            .is_err());
+
+        let gregory = Calendar::from_str("gregory").unwrap();
+        let base = PlainDate::new(2020, 3, 15, gregory).unwrap();
+        let partial = PartialDate {
+            day: Some(20),
+            ..Default::default()
+        };
+        let with_day = base.with(partial, None).unwrap();
+        assert_eq!(
+            (
+                with_day.iso_year(),
+                with_day.iso_month(),
+                with_day.iso_day()
+            ),
+            (2020, 3, 20)
+        );
+
+        let partial = PartialDate {
+            era: Some(tinystr!(19, "bce")),
+            ..Default::default()
+        };
+        let with_era = base.with(partial, None).unwrap();
+        assert_eq!(with_era.iso_year(), -2019);
+
+        let matching = PartialDate {
+            year: Some(2021),
+            era: Some(tinystr!(19, "ce")),
+            era_year: Some(2021),
+            ..Default::default()
+        };
+        assert_eq!(base.with(matching, None).unwrap().iso_year(), 2021);
+        let conflicting = PartialDate {
+            year: Some(2019),
+            ..matching
+        };
+        assert!(base.with(conflicting, None).is_err());
+    }
+
+    #[test]
+    fn compare_and_equals_across_calendars() {
+        let iso = PlainDate::try_new(2024, 5, 4, Calendar::default()).unwrap();
+        let gregory =
+            PlainDate::try_new(2024, 5, 4, Calendar::from_str("gregory").unwrap()).unwrap();
+        assert_eq!(
+            PlainDate::compare(&iso, &gregory),
+            core::cmp::Ordering::Equal
+        );
+        assert_ne!(iso, gregory);
+        assert_eq!(iso, iso.clone());
+
+        let later = PlainDate::try_new(2024, 5, 5, Calendar::default()).unwrap();
+        assert_eq!(
+            PlainDate::compare(&gregory, &later),
+            core::cmp::Ordering::Less
+        );
+        assert_eq!(
+            PlainDate::compare(&later, &iso),
+            core::cmp::Ordering::Greater
+        );
+
+        // Dates in different calendars are distinct keys.
+        let dates: std::collections::HashSet<_> = [iso.clone(), gregory, iso].into_iter().collect();
+        assert_eq!(dates.len(), 2);
+    }
+
+    #[test]
+    fn with_calendar_preserves_iso_date() {
+        let iso = PlainDate::from_str("2019-05-01").unwrap();
+        assert_eq!(iso.era().unwrap(), None);
+
+        let gregory = iso.with_calendar(Calendar::from_str("gregory").unwrap());
+        assert_eq!(
+            PlainDate::compare(&iso, &gregory),
+            core::cmp::Ordering::Equal
+        );
+        assert_eq!(gregory.era().unwrap(), Some(tinystr!(16, "ce")));
+        assert_eq!(gregory.era_year().unwrap(), Some(2019));
+        assert_eq!(gregory.with_calendar(Calendar::default()), iso);
+
+        let japanese = iso.with_calendar(Calendar::from_str("japanese").unwrap());
+        assert_eq!(japanese.era().unwrap(), Some(tinystr!(16, "reiwa")));
+        assert_eq!(japanese.year().unwrap(), 1);
+        assert_eq!(
+            (
+                japanese.iso_year(),
+                japanese.iso_month(),
+                japanese.iso_day()
+            ),
+            (2019, 5, 1)
+        );
+
+        let bce = PlainDate::from_str("-000001-01-01")
+            .unwrap()
+            .with_calendar(Calendar::from_str("gregory").unwrap());
+        assert_eq!(bce.era().unwrap(), Some(tinystr!(16, "bce")));
+        assert_eq!(bce.era_year().unwrap(), Some(2));
+    }
+
+    #[test]
+    fn expanded_year_strings() {
+        let max = PlainDate::from_str("+275760-09-13").unwrap();
+        assert_eq!(
+            (max.iso_year(), max.iso_month(), max.iso_day()),
+            (275_760, 9, 13)
+        );
+        assert!(PlainDate::from_str("+275760-09-14").is_err());
+
+        let min = PlainDate::from_str("-271821-04-19").unwrap();
+        assert_eq!(
+            (min.iso_year(), min.iso_month(), min.iso_day()),
+            (-271_821, 4, 19)
+        );
+        assert!(PlainDate::from_str("-271821-04-18").is_err());
+
+        // Year -1 is 2 BCE in the proleptic Gregorian calendar, as year 0 is 1 BCE.
+        let date = PlainDate::from_str("-000001-01-01").unwrap();
+        assert_eq!(date.iso_year(), -1);
+        assert_eq!(PlainDate::from_str("+000000-01-01").unwrap().iso_year(), 0);
+        assert_eq!(
+            PlainDate::from_str("+002024-01-01").unwrap().iso_year(),
+            2024
+        );
+
+        // A sign requires six digits, and negative zero is not a valid year.
+        assert!(PlainDate::from_str("+2024-01-01").is_err());
+        assert!(PlainDate::from_str("-2024-01-01").is_err());
+        assert!(PlainDate::from_str("-000000-01-01").is_err());
+    }
+
     // test262/test/built-ins/Temporal/Calendar/prototype/month/argument-string-invalid.js
     #[test]
     fn invalid_strings() {
@@ -841,4 +1411,155 @@ mod tests {
             assert!(PlainDate::from_str(s).is_err())
         }
     }
+
+    #[test]
+    fn new_constrain_and_era() {
+        let constrained = PlainDate::new(2023, 2, 31, Calendar::default()).unwrap();
+        assert_eq!(constrained.iso_day(), 28);
+        assert!(PlainDate::try_new(2023, 2, 31, Calendar::default()).is_err());
+
+        let gregory = Calendar::from_str("gregory").unwrap();
+        let ides = PlainDate::new_with_era(
+            "bce",
+            44,
+            3,
+            15,
+            gregory.clone(),
+            ArithmeticOverflow::Reject,
+        )
+        .unwrap();
+        assert_eq!(
+            (ides.iso_year(), ides.iso_month(), ides.iso_day()),
+            (-43, 3, 15)
+        );
+
+        let ce = PlainDate::new_with_era(
+            "ce",
+            2024,
+            5,
+            4,
+            gregory.clone(),
+            ArithmeticOverflow::Constrain,
+        )
+        .unwrap();
+        assert_eq!(ce.iso_year(), 2024);
+
+        assert!(PlainDate::new_with_era(
+            "foo",
+            44,
+            3,
+            15,
+            gregory.clone(),
+            ArithmeticOverflow::Constrain
+        )
+        .is_err());
+        assert!(
+            PlainDate::new_with_era("bce", 0, 3, 15, gregory, ArithmeticOverflow::Constrain)
+                .is_err()
+        );
+        assert!(PlainDate::new_with_era(
+            "bce",
+            44,
+            3,
+            15,
+            Calendar::default(),
+            ArithmeticOverflow::Constrain
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn week_of_year_iso_and_us_conventions() {
+        let weeks_of = |year, month, day| {
+            let date = PlainDate::try_new(year, month, day, Calendar::default()).unwrap();
+            let iso = date.week_of_year_with(Weekday::Monday, 4).unwrap();
+            assert_eq!(iso, date.iso_week_of_year());
+            let us = date.week_of_year_with(Weekday::Sunday, 1).unwrap();
+            ((iso.week, iso.year), (us.week, us.year))
+        };
+        // 2023-01-01 is a Sunday.
+        assert_eq!(weeks_of(2023, 1, 1), ((52, 2022), (1, 2023)));
+        // 2024-01-01 is a Monday.
+        assert_eq!(weeks_of(2024, 1, 1), ((1, 2024), (1, 2024)));
+        assert_eq!(weeks_of(2023, 12, 31), ((52, 2023), (1, 2024)));
+        assert_eq!(weeks_of(2024, 12, 28), ((52, 2024), (52, 2024)));
+        assert_eq!(weeks_of(2024, 12, 29), ((52, 2024), (1, 2025)));
+        assert_eq!(weeks_of(2024, 12, 30), ((1, 2025), (1, 2025)));
+
+        let date = PlainDate::try_new(2024, 5, 4, Calendar::default()).unwrap();
+        assert!(date.week_of_year_with(Weekday::Monday, 0).is_err());
+        assert!(date.week_of_year_with(Weekday::Monday, 8).is_err());
+    }
+
+    #[test]
+    fn iso_week_of_year_boundaries() {
+        let week_of = |year, month, day| {
+            PlainDate::try_new(year, month, day, Calendar::default())
+                .unwrap()
+                .iso_week_of_year()
+        };
+        // 2020 and 2015 are 53-week years.
+        assert_eq!(
+            week_of(2020, 12, 31),
+            WeekInfo {
+                week: 53,
+                year: 2020
+            }
+        );
+        assert_eq!(
+            week_of(2021, 1, 3),
+            WeekInfo {
+                week: 53,
+                year: 2020
+            }
+        );
+        assert_eq!(
+            week_of(2021, 1, 4),
+            WeekInfo {
+                week: 1,
+                year: 2021
+            }
+        );
+        assert_eq!(
+            week_of(2015, 12, 31),
+            WeekInfo {
+                week: 53,
+                year: 2015
+            }
+        );
+        assert_eq!(
+            week_of(2016, 1, 1),
+            WeekInfo {
+                week: 53,
+                year: 2015
+            }
+        );
+        // Dec 31st may belong to the next week-numbering year.
+        assert_eq!(
+            week_of(2019, 12, 31),
+            WeekInfo {
+                week: 1,
+                year: 2020
+            }
+        );
+        assert_eq!(
+            week_of(2019, 12, 29),
+            WeekInfo {
+                week: 52,
+                year: 2019
+            }
+        );
+        assert_eq!(
+            week_of(1969, 12, 29),
+            WeekInfo {
+                week: 1,
+                year: 1970
+            }
+        );
+
+        let date = PlainDate::try_new(2020, 12, 31, Calendar::default()).unwrap();
+        assert_eq!(date.days_in_week().unwrap(), 7);
+        assert_eq!(date.week_of_year().unwrap(), Some(53));
+        assert_eq!(date.year_of_week().unwrap(), Some(2020));
+    }
 }
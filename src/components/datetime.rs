@@ -7,18 +7,21 @@ use crate::{
         ArithmeticOverflow, DifferenceOperation, DifferenceSettings, ResolvedRoundingOptions,
         RoundingOptions, TemporalUnit,
     },
-    parsers::parse_date_time,
-    temporal_assert, Sign, TemporalError, TemporalResult, TemporalUnwrap,
+    parsers::parse_plain_date_time,
+    temporal_assert,
+    utils::validate_offset_nanoseconds,
+    Sign, TemporalError, TemporalResult, TemporalUnwrap, NS_PER_DAY,
 };
 
+use core::{cmp::Ordering, str::FromStr};
+use ixdtf::parsers::records::IxdtfParseRecord;
 use num_traits::AsPrimitive;
-use std::{cmp::Ordering, str::FromStr};
 use tinystr::TinyAsciiStr;
 
 use super::{
     calendar::{CalendarDateLike, GetTemporalCalendar},
     duration::normalized::{NormalizedTimeDuration, RelativeRoundResult},
-    Duration, PartialDate, PartialTime, PlainDate, PlainTime,
+    Duration, PartialDate, PartialTime, PlainDate, PlainTime, Weekday,
 };
 
 /// A partial PlainDateTime record
@@ -32,7 +35,7 @@ pub struct PartialDateTime {
 
 /// The native Rust implementation of `Temporal.PlainDateTime`
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct PlainDateTime {
     pub(crate) iso: IsoDateTime,
     calendar: Calendar,
@@ -67,14 +70,14 @@ impl PlainDateTime {
         IsoDateTime::new_unchecked(iso, IsoTime::noon()).is_within_limits()
     }
 
-    /// Create a new `DateTime` from an `Instant`.
+    /// Create a new `DateTime` from an `Instant` and an offset in nanoseconds.
     #[inline]
-    pub(crate) fn from_instant(
+    pub(crate) fn from_instant_with_calendar(
         instant: &Instant,
-        offset: f64,
+        offset_nanos: i128,
         calendar: Calendar,
     ) -> TemporalResult<Self> {
-        let iso = IsoDateTime::from_epoch_nanos(&instant.epoch_nanos, offset)?;
+        let iso = IsoDateTime::from_epoch_nanos(&instant.epoch_nanos, offset_nanos)?;
         Ok(Self { iso, calendar })
     }
 
@@ -216,6 +219,36 @@ impl PlainDateTime {
         // roundingIncrement, smallestUnit, roundingMode).
         diff.round_relative_duration(dest_epoch_ns, self, None, options)
     }
+
+    /// Creates a `PlainDateTime` from the date, time, and calendar of a parsed record.
+    pub(crate) fn from_parse_record(parse_record: &IxdtfParseRecord) -> TemporalResult<Self> {
+        let calendar = parse_record.calendar.unwrap_or("iso8601");
+
+        let time = if let Some(time) = parse_record.time {
+            IsoTime::from_components(
+                i32::from(time.hour),
+                i32::from(time.minute),
+                i32::from(time.second),
+                f64::from(time.nanosecond),
+            )?
+        } else {
+            IsoTime::default()
+        };
+
+        let parsed_date = parse_record.date.temporal_unwrap()?;
+
+        let date = IsoDate::new_with_overflow(
+            parsed_date.year,
+            parsed_date.month.into(),
+            parsed_date.day.into(),
+            ArithmeticOverflow::Reject,
+        )?;
+
+        Ok(Self::new_unchecked(
+            IsoDateTime::new(date, time)?,
+            Calendar::from_str(calendar)?,
+        ))
+    }
 }
 
 // ==== Public PlainDateTime API ====
@@ -311,6 +344,37 @@ impl PlainDateTime {
         ))
     }
 
+    /// Creates an ISO `DateTime` for the local wall-clock time of an `Instant` at a fixed offset.
+    ///
+    /// The offset must be less than a day, i.e. within `-1439..=1439` minutes.
+    pub fn from_instant(instant: &Instant, offset_minutes: i32) -> TemporalResult<Self> {
+        let offset_nanos = validate_offset_nanoseconds(offset_minutes)?;
+        Self::from_instant_with_calendar(instant, offset_nanos, Calendar::default())
+    }
+
+    /// Creates an ISO `DateTime` for the local wall-clock time of the epoch nanoseconds at a
+    /// fixed offset.
+    pub fn from_epoch_nanoseconds(
+        epoch_nanoseconds: i128,
+        offset_minutes: i32,
+    ) -> TemporalResult<Self> {
+        Self::from_instant(&Instant::new(epoch_nanoseconds)?, offset_minutes)
+    }
+
+    /// Returns the exact `Instant` of this wall-clock `DateTime` at a fixed offset, the inverse
+    /// of `from_instant`.
+    ///
+    /// The offset must be less than a day, i.e. within `-1439..=1439` minutes. The epoch
+    /// nanoseconds are calculated with exact integer arithmetic, and the result must be within
+    /// the valid range of an `Instant`.
+    pub fn to_instant_with_offset(&self, offset_minutes: i32) -> TemporalResult<Instant> {
+        let offset_nanos = validate_offset_nanoseconds(offset_minutes)?;
+        let epoch_nanos = i128::from(self.iso.date.to_epoch_days()) * i128::from(NS_PER_DAY)
+            + i128::from(self.iso.time.to_nanoseconds_since_midnight())
+            - offset_nanos;
+        Instant::new(epoch_nanos)
+    }
+
     /// Create a `DateTime` from a `Date` and a `Time`.
     pub fn from_date_and_time(date: PlainDate, time: PlainTime) -> TemporalResult<Self> {
         Ok(Self::new_unchecked(
@@ -480,9 +544,15 @@ impl PlainDateTime {
         self.calendar.day(&CalendarDateLike::DateTime(self))
     }
 
-    /// Returns the calendar day of week value.
-    pub fn day_of_week(&self) -> TemporalResult<u16> {
-        self.calendar.day_of_week(&CalendarDateLike::DateTime(self))
+    /// Returns the calendar day of week value as a `Weekday`.
+    pub fn day_of_week(&self) -> TemporalResult<Weekday> {
+        let number = self
+            .calendar
+            .day_of_week(&CalendarDateLike::DateTime(self))?;
+        u8::try_from(number)
+            .ok()
+            .and_then(Weekday::from_number)
+            .temporal_unwrap()
     }
 
     /// Returns the calendar day of year value.
@@ -614,49 +684,27 @@ impl From<PlainDate> for PlainDateTime {
 impl FromStr for PlainDateTime {
     type Err = TemporalError;
 
+    /// Parses a `PlainDateTime` with either a `T` or a space separating the date and time.
+    ///
+    /// A UTC offset is ignored, but a UTC designator, i.e. `Z`, is rejected.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parse_record = parse_date_time(s)?;
-
-        let calendar = parse_record.calendar.unwrap_or("iso8601");
-
-        let time = if let Some(time) = parse_record.time {
-            IsoTime::from_components(
-                i32::from(time.hour),
-                i32::from(time.minute),
-                i32::from(time.second),
-                f64::from(time.nanosecond),
-            )?
-        } else {
-            IsoTime::default()
-        };
-
-        let parsed_date = parse_record.date.temporal_unwrap()?;
-
-        let date = IsoDate::new_with_overflow(
-            parsed_date.year,
-            parsed_date.month.into(),
-            parsed_date.day.into(),
-            ArithmeticOverflow::Reject,
-        )?;
-
-        Ok(Self::new_unchecked(
-            IsoDateTime::new(date, time)?,
-            Calendar::from_str(calendar)?,
-        ))
+        let (record, _) = parse_plain_date_time(s)?;
+        Self::from_parse_record(&record)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use core::str::FromStr;
 
     use tinystr::{tinystr, TinyAsciiStr};
 
     use crate::{
         components::{
             calendar::Calendar, duration::DateDuration, Duration, PartialDate, PartialDateTime,
-            PartialTime, PlainDateTime,
+            PartialTime, PlainDate, PlainDateTime,
         },
+        error::ErrorKind,
         options::{
             DifferenceSettings, RoundingIncrement, RoundingOptions, TemporalRoundingMode,
             TemporalUnit,
@@ -994,6 +1042,97 @@ mod tests {
         assert_eq!(result.minutes(), 30.0);
     }
 
+    #[test]
+    fn from_str_separators_offsets_and_utc_designator() {
+        let expected =
+            PlainDateTime::try_new(2024, 5, 4, 12, 5, 24, 500, 0, 0, Calendar::default()).unwrap();
+        for s in [
+            "2024-05-04T12:05:24.5",
+            "2024-05-04t12:05:24.5",
+            "2024-05-04 12:05:24.5",
+            // A UTC offset is ignored by a plain type.
+            "2024-05-04T12:05:24.5-05:00",
+            "2024-05-04 12:05:24.5+05:30[u-ca=iso8601]",
+            "2024-05-04T12:05:24.5[!u-ca=iso8601]",
+        ] {
+            assert_eq!(PlainDateTime::from_str(s).unwrap(), expected, "{s}");
+        }
+
+        let gregory = PlainDateTime::from_str("2024-05-04 12:05:24.5[u-ca=gregory]").unwrap();
+        assert_eq!(gregory.calendar().identifier(), "gregory");
+        assert_eq!(gregory.iso, expected.iso);
+
+        // A UTC designator implies an exact time, so it is rejected.
+        for s in [
+            "2024-05-04T12:05:24.5Z",
+            "2024-05-04 12:05:24.5z",
+            "2024-05-04T12:05:24.5Z[UTC]",
+            "2024-05-04T12:05:24.5Z[!UTC][u-ca=iso8601]",
+        ] {
+            let err = PlainDateTime::from_str(s).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range, "{s}");
+            assert!(PlainDate::from_str(s).is_err(), "{s}");
+        }
+
+        // A `Z` in an annotation is not a UTC designator.
+        assert!(PlainDateTime::from_str("2024-05-04T12:05:24.5[Europe/Zurich]").is_ok());
+    }
+
+    #[test]
+    fn dt_diff_borrows_a_day_for_the_time() {
+        let dt = |month, day, hour| {
+            PlainDateTime::try_new(2024, month, day, hour, 0, 0, 0, 0, 0, Calendar::default())
+                .unwrap()
+        };
+        let largest = |unit| DifferenceSettings {
+            largest_unit: Some(unit),
+            ..Default::default()
+        };
+        let assert_fields = |result: Duration, expected: (f64, f64, f64, f64)| {
+            assert_eq!(result.years(), 0.0);
+            assert_eq!(result.months(), expected.0);
+            assert_eq!(result.weeks(), 0.0);
+            assert_eq!(result.days(), expected.1);
+            assert_eq!(result.hours(), expected.2);
+            assert_eq!(result.minutes(), expected.3);
+        };
+
+        // The end time of day is earlier than the start, so a day is borrowed before the
+        // calendar difference: 2024-01-31 until 2024-02-29 does not reach a full month.
+        let start = dt(1, 31, 23);
+        let end = dt(3, 1, 1);
+        assert_fields(
+            start.until(&end, largest(TemporalUnit::Month)).unwrap(),
+            (0.0, 29.0, 2.0, 0.0),
+        );
+        assert_fields(
+            start.until(&end, largest(TemporalUnit::Year)).unwrap(),
+            (0.0, 29.0, 2.0, 0.0),
+        );
+        assert_fields(
+            start.until(&end, largest(TemporalUnit::Hour)).unwrap(),
+            (0.0, 0.0, 698.0, 0.0),
+        );
+        assert_fields(
+            end.since(&start, largest(TemporalUnit::Month)).unwrap(),
+            (0.0, 29.0, 2.0, 0.0),
+        );
+
+        // The start month day is constrained before the remaining days are counted.
+        assert_fields(
+            start
+                .until(&dt(3, 31, 1), largest(TemporalUnit::Month))
+                .unwrap(),
+            (1.0, 30.0, 2.0, 0.0),
+        );
+        assert_fields(
+            dt(3, 31, 1)
+                .until(&start, largest(TemporalUnit::Month))
+                .unwrap(),
+            (-1.0, -28.0, -2.0, 0.0),
+        );
+    }
+
     #[test]
     fn dt_round_basic() {
         let assert_datetime =
@@ -1051,4 +1190,118 @@ mod tests {
             .unwrap();
         assert_datetime(result, (1976, 11, 18, 14, 23, 30, 123, 456, 790));
     }
+
+    #[test]
+    fn from_instant_with_offset() {
+        // 2023-11-14T22:13:20.123456789Z
+        let dt = PlainDateTime::from_epoch_nanoseconds(1_700_000_000_123_456_789, 0).unwrap();
+        assert_datetime(
+            dt,
+            (2023, 11, tinystr!(4, "M11"), 14, 22, 13, 20, 123, 456, 789),
+        );
+
+        // Pre-epoch values floor towards the preceding day.
+        let dt = PlainDateTime::from_epoch_nanoseconds(-1, 0).unwrap();
+        assert_datetime(
+            dt,
+            (1969, 12, tinystr!(4, "M12"), 31, 23, 59, 59, 999, 999, 999),
+        );
+
+        let dt = PlainDateTime::from_epoch_nanoseconds(0, -300).unwrap();
+        assert_datetime(dt, (1969, 12, tinystr!(4, "M12"), 31, 19, 0, 0, 0, 0, 0));
+
+        let dt = PlainDateTime::from_epoch_nanoseconds(0, 330).unwrap();
+        assert_datetime(dt, (1970, 1, tinystr!(4, "M01"), 1, 5, 30, 0, 0, 0, 0));
+
+        // -271821-04-20T00:00:00Z
+        let dt =
+            PlainDateTime::from_epoch_nanoseconds(-8_640_000_000_000_000_000_000, -1439).unwrap();
+        assert_datetime(dt, (-271_821, 4, tinystr!(4, "M04"), 19, 0, 1, 0, 0, 0, 0));
+
+        assert!(PlainDateTime::from_epoch_nanoseconds(0, 1440).is_err());
+        assert!(PlainDateTime::from_epoch_nanoseconds(8_640_000_000_000_000_000_001, 0).is_err());
+    }
+
+    #[test]
+    fn add_balances_time_overflow_into_date() {
+        let add = |dt: &str, duration: &str| {
+            PlainDateTime::from_str(dt)
+                .unwrap()
+                .add(&Duration::from_str(duration).unwrap(), None)
+                .unwrap()
+        };
+
+        let result = add("2024-01-31T23:59:59.999999999", "PT0.000000001S");
+        assert_datetime(result, (2024, 2, tinystr!(4, "M02"), 1, 0, 0, 0, 0, 0, 0));
+        let result = add("2023-12-31T23:00", "PT1H");
+        assert_datetime(result, (2024, 1, tinystr!(4, "M01"), 1, 0, 0, 0, 0, 0, 0));
+        let result = add("2024-02-28T12:00", "PT12H");
+        assert_datetime(result, (2024, 2, tinystr!(4, "M02"), 29, 0, 0, 0, 0, 0, 0));
+        let result = add("2023-02-28T12:00", "PT12H");
+        assert_datetime(result, (2023, 3, tinystr!(4, "M03"), 1, 0, 0, 0, 0, 0, 0));
+        // 86400 seconds and a day carry two days past the leap day.
+        let result = add("2024-02-28T06:00", "P1DT86400S");
+        assert_datetime(result, (2024, 3, tinystr!(4, "M03"), 1, 6, 0, 0, 0, 0, 0));
+        let result = add("2024-03-01T00:00", "-PT0.000000001S");
+        assert_datetime(
+            result,
+            (2024, 2, tinystr!(4, "M02"), 29, 23, 59, 59, 999, 999, 999),
+        );
+        let result = add("2025-01-01T01:00", "-PT49H");
+        assert_datetime(result, (2024, 12, tinystr!(4, "M12"), 30, 0, 0, 0, 0, 0, 0));
+
+        // Balancing past the limits is an error.
+        let max = PlainDateTime::from_str("+275760-09-13T23:00").unwrap();
+        assert!(max.add(&Duration::from_str("PT1H").unwrap(), None).is_err());
+    }
+
+    #[test]
+    fn to_instant_with_offset() {
+        let dt = PlainDateTime::new(1969, 12, 31, 23, 59, 59, 999, 999, 999, Calendar::default())
+            .unwrap();
+        assert_eq!(dt.to_instant_with_offset(0).unwrap().epoch_nanos, -1);
+        // 1969-12-31T23:59:59.999999999-05:00 is 1970-01-01T04:59:59.999999999Z
+        assert_eq!(
+            dt.to_instant_with_offset(-300).unwrap().epoch_nanos,
+            5 * 3_600_000_000_000 - 1
+        );
+
+        // 1901-07-04T12:30:00+05:30 is 1901-07-04T07:00:00Z
+        let dt = PlainDateTime::new(1901, 7, 4, 12, 30, 0, 0, 0, 0, Calendar::default()).unwrap();
+        let instant = dt.to_instant_with_offset(330).unwrap();
+        assert_eq!(instant.epoch_nanos, -2_161_530_000_000_000_000);
+        assert_eq!(PlainDateTime::from_instant(&instant, 330).unwrap(), dt);
+
+        let dt = PlainDateTime::from_epoch_nanoseconds(1_700_000_000_123_456_789, -420).unwrap();
+        assert_eq!(
+            dt.to_instant_with_offset(-420).unwrap().epoch_nanos,
+            1_700_000_000_123_456_789
+        );
+
+        // The limits of a local day may fall outside of the valid instant range.
+        let min =
+            PlainDateTime::new(-271_821, 4, 20, 0, 0, 0, 0, 0, 0, Calendar::default()).unwrap();
+        assert_eq!(
+            min.to_instant_with_offset(0).unwrap().epoch_nanos,
+            -8_640_000_000_000_000_000_000
+        );
+        assert!(min.to_instant_with_offset(1).is_err());
+        let max =
+            PlainDateTime::new(275_760, 9, 13, 0, 0, 0, 0, 0, 0, Calendar::default()).unwrap();
+        assert!(max.to_instant_with_offset(0).is_ok());
+        assert!(max.to_instant_with_offset(-1).is_err());
+        assert!(max.to_instant_with_offset(1440).is_err());
+    }
+
+    #[test]
+    fn expanded_year_limits() {
+        let max = PlainDateTime::from_str("+275760-09-13T23:59:59.999999999").unwrap();
+        assert_eq!(max.iso_year(), 275_760);
+        assert!(PlainDateTime::from_str("+275760-09-14T00:00").is_err());
+
+        // The limits are exclusive, which requires nanosecond precision at the boundary.
+        assert!(PlainDateTime::from_str("-271821-04-19T00:00").is_err());
+        let min = PlainDateTime::from_str("-271821-04-19T00:00:00.000000001").unwrap();
+        assert_eq!((min.iso_year(), min.nanosecond()), (-271_821, 1));
+    }
 }
@@ -1,15 +1,18 @@
 //! This module implements `Duration` along with it's methods and components.
 
 use crate::{
-    components::{PlainDateTime, PlainTime},
+    components::{Instant, PlainDateTime, PlainTime, ZonedDateTime},
     iso::{IsoDateTime, IsoTime},
-    options::{RelativeTo, ResolvedRoundingOptions, RoundingOptions, TemporalUnit},
+    options::{
+        ArithmeticOverflow, DifferenceSettings, RelativeTo, ResolvedRoundingOptions,
+        RoundingOptions, TemporalUnit,
+    },
     primitive::FiniteF64,
     temporal_assert, Sign, TemporalError, TemporalResult,
 };
+use core::{cmp::Ordering, str::FromStr};
 use ixdtf::parsers::{records::TimeDurationRecord, IsoDurationParser};
 use num_traits::AsPrimitive;
-use std::str::FromStr;
 
 use self::normalized::NormalizedTimeDuration;
 
@@ -144,6 +147,28 @@ impl Duration {
             .map(|x| TemporalUnit::from(10 - x.0))
             .unwrap_or(TemporalUnit::Nanosecond)
     }
+
+    /// Creates a `Duration` from a `DateDuration` and a `NormalizedTimeDuration`, balancing the
+    /// time duration up to `largest_unit` and adding any resulting days to the date days.
+    pub(crate) fn from_date_and_normalized(
+        date: &DateDuration,
+        norm: NormalizedTimeDuration,
+        largest_unit: TemporalUnit,
+    ) -> TemporalResult<Self> {
+        let (days, time) = TimeDuration::from_normalized(norm, largest_unit)?;
+        Self::new(
+            date.years,
+            date.months,
+            date.weeks,
+            date.days.checked_add(&days)?,
+            time.hours,
+            time.minutes,
+            time.seconds,
+            time.milliseconds,
+            time.microseconds,
+            time.nanoseconds,
+        )
+    }
 }
 
 // ==== Public Duration API ====
@@ -207,12 +232,65 @@ impl Duration {
         }
     }
 
-    /// Creates a `Duration` from a provided `PartialDuration`.
+    /// Creates a `Duration` representing the difference from `start` until `end`.
+    ///
+    /// The largest unit defaults to seconds and may be at most hours, or days when explicitly
+    /// requested, which are counted as exactly 24 hours. Year, month, and week largest units are
+    /// rejected as instants have no calendar.
+    pub fn between_instants(
+        start: &Instant,
+        end: &Instant,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<Self> {
+        match settings.largest_unit {
+            Some(unit) if unit.is_calendar_unit() => Err(TemporalError::range()
+                .with_message("largestUnit cannot be a calendar unit for an Instant difference.")),
+            Some(TemporalUnit::Day) => {
+                let settings = DifferenceSettings {
+                    largest_unit: Some(TemporalUnit::Hour),
+                    ..settings
+                };
+                // Split the days from the rounded difference in exact nanoseconds.
+                let norm = NormalizedTimeDuration::from_time_duration(&start.until(end, settings)?);
+                let (days, time) = TimeDuration::from_normalized(norm, TemporalUnit::Day)?;
+                Ok(Self::from_day_and_time(days, &time))
+            }
+            _ => Ok(Self::from_day_and_time(
+                FiniteF64::default(),
+                &start.until(end, settings)?,
+            )),
+        }
+    }
+
+    /// Creates a `Duration` from a provided `PartialDuration`, treating unset fields as zero.
+    ///
+    /// Returns a `TypeError` if every field is unset, and a `RangeError` if a field is not an
+    /// integer or the fields do not share a single sign.
     pub fn from_partial_duration(partial: PartialDuration) -> TemporalResult<Self> {
         if partial == PartialDuration::default() {
             return Err(TemporalError::r#type()
                 .with_message("PartialDuration cannot have all empty fields."));
         }
+        // NOTE: `ToTemporalPartialDurationRecord` converts each field with `ToIntegerIfIntegral`.
+        let fields = [
+            partial.years,
+            partial.months,
+            partial.weeks,
+            partial.days,
+            partial.hours,
+            partial.minutes,
+            partial.seconds,
+            partial.milliseconds,
+            partial.microseconds,
+            partial.nanoseconds,
+        ];
+        if fields
+            .iter()
+            .flatten()
+            .any(|field| field.as_inner().fract() != 0.0)
+        {
+            return Err(TemporalError::range().with_message("Duration fields must be integers."));
+        }
         Self::new(
             partial.years.unwrap_or_default(),
             partial.months.unwrap_or_default(),
@@ -227,6 +305,29 @@ impl Duration {
         )
     }
 
+    /// Converts this `Duration` into a `core::time::Duration`.
+    ///
+    /// Days are treated as 24 hours. Returns a `RangeError` if the `Duration` contains years,
+    /// months, or weeks, or if it is negative.
+    pub fn to_std(&self) -> TemporalResult<core::time::Duration> {
+        if !self.years().is_zero() || !self.months().is_zero() || !self.weeks().is_zero() {
+            return Err(TemporalError::range().with_message(
+                "A Duration with years, months, or weeks cannot be converted to a std Duration.",
+            ));
+        }
+        if self.sign() == Sign::Negative {
+            return Err(TemporalError::range()
+                .with_message("A negative Duration cannot be converted to a std Duration."));
+        }
+        let nanoseconds =
+            NormalizedTimeDuration::from_time_duration(self.time()).add_days(self.days().as_())?;
+        // NOTE: A valid normalized time duration is less than 2^53 seconds, so it fits a `u64`.
+        Ok(core::time::Duration::new(
+            (nanoseconds.0 / 1_000_000_000) as u64,
+            (nanoseconds.0 % 1_000_000_000) as u32,
+        ))
+    }
+
     /// Return if the Durations values are within their valid ranges.
     #[inline]
     #[must_use]
@@ -245,7 +346,8 @@ impl Duration {
         &self.time
     }
 
-    /// Returns a reference to the inner `DateDuration`
+    /// Returns a reference to the inner `DateDuration`, i.e. the `years`, `months`, `weeks`, and
+    /// `days` fields.
     #[inline]
     #[must_use]
     pub fn date(&self) -> &DateDuration {
@@ -279,7 +381,7 @@ impl Duration {
         self.date.weeks
     }
 
-    /// Returns the `weeks` field of duration.
+    /// Returns the `days` field of duration.
     #[inline]
     #[must_use]
     pub const fn days(&self) -> FiniteF64 {
@@ -293,7 +395,7 @@ impl Duration {
         self.time.hours
     }
 
-    /// Returns the `hours` field of duration.
+    /// Returns the `minutes` field of duration.
     #[inline]
     #[must_use]
     pub const fn minutes(&self) -> FiniteF64 {
@@ -307,7 +409,7 @@ impl Duration {
         self.time.seconds
     }
 
-    /// Returns the `hours` field of duration.
+    /// Returns the `milliseconds` field of duration.
     #[inline]
     #[must_use]
     pub const fn milliseconds(&self) -> FiniteF64 {
@@ -368,9 +470,80 @@ impl Duration {
         }
     }
 
+    /// Compares two `Duration`s, returning the `Ordering` of `one` relative to `two`.
+    ///
+    /// Calendar units and, with a `ZonedDateTime` anchor, days are resolved against
+    /// `relative_to`. Pure time durations are compared by their normalized nanoseconds.
+    ///
+    /// Equivalent to `Temporal.Duration.compare`.
+    pub fn compare(
+        one: &Self,
+        two: &Self,
+        relative_to: Option<RelativeTo>,
+    ) -> TemporalResult<Ordering> {
+        // 5. If one.[[Years]] = two.[[Years]], and ... one.[[Nanoseconds]] = two.[[Nanoseconds]], then
+        if one.fields() == two.fields() {
+            // a. Return +0𝔽.
+            return Ok(Ordering::Equal);
+        }
+
+        let plain_relative_to = relative_to.as_ref().and_then(RelativeTo::as_plain_date);
+        let zoned_relative_to = relative_to
+            .as_ref()
+            .and_then(RelativeTo::as_zoned_date_time);
+
+        // 6. Let largestUnit1 be DefaultTemporalLargestUnit(one).
+        let largest_one = one.default_largest_unit();
+        // 7. Let largestUnit2 be DefaultTemporalLargestUnit(two).
+        let largest_two = two.default_largest_unit();
+
+        // 10. If zonedRelativeTo is not undefined, and either TemporalUnitCategory(largestUnit1) or
+        // TemporalUnitCategory(largestUnit2) is date, then
+        if let Some(zdt) = zoned_relative_to {
+            if largest_one >= TemporalUnit::Day || largest_two >= TemporalUnit::Day {
+                // a-d. Let after1 and after2 be ? AddZonedDateTime(zonedRelativeTo.[[EpochNanoseconds]], ...).
+                let after_one = zdt.add_as_instant(one, ArithmeticOverflow::Constrain)?;
+                let after_two = zdt.add_as_instant(two, ArithmeticOverflow::Constrain)?;
+                // e. Return 𝔽(CompareEpochNanoseconds(after1, after2)).
+                return Ok(after_one.cmp(&after_two));
+            }
+        }
+
+        // 11. If IsCalendarUnit(largestUnit1) is true or IsCalendarUnit(largestUnit2) is true, then
+        let (days_one, days_two) =
+            if largest_one.is_calendar_unit() || largest_two.is_calendar_unit() {
+                // a. If plainRelativeTo is undefined, throw a RangeError exception.
+                let Some(plain_date) = plain_relative_to else {
+                    return Err(TemporalError::range()
+                        .with_message("A relativeTo is required to compare calendar units."));
+                };
+                // b. Let days1 be ? DateDurationDays(duration1.[[Date]], plainRelativeTo).
+                // c. Let days2 be ? DateDurationDays(duration2.[[Date]], plainRelativeTo).
+                (
+                    plain_date.date_duration_days(one.date())?,
+                    plain_date.date_duration_days(two.date())?,
+                )
+            // 12. Else,
+            } else {
+                // a. Let days1 be one.[[Days]].
+                // b. Let days2 be two.[[Days]].
+                (one.days().as_(), two.days().as_())
+            };
+
+        // 13. Let timeDuration1 be ? Add24HourDaysToTimeDuration(duration1.[[Time]], days1).
+        let norm_one = NormalizedTimeDuration::from_time_duration(one.time()).add_days(days_one)?;
+        // 14. Let timeDuration2 be ? Add24HourDaysToTimeDuration(duration2.[[Time]], days2).
+        let norm_two = NormalizedTimeDuration::from_time_duration(two.time()).add_days(days_two)?;
+
+        // 15. Return 𝔽(CompareTimeDuration(timeDuration1, timeDuration2)).
+        Ok(norm_one.0.cmp(&norm_two.0))
+    }
+
     /// Returns the result of adding a `Duration` to the current `Duration`
+    ///
+    /// A `relative_to` is required when either duration contains years, months, or weeks.
     #[inline]
-    pub fn add(&self, other: &Self) -> TemporalResult<Self> {
+    pub fn add(&self, other: &Self, relative_to: Option<RelativeTo>) -> TemporalResult<Self> {
         // NOTE: Implemented from AddDurations
         // Steps 1-22 are functionally useless in this context.
 
@@ -380,42 +553,165 @@ impl Duration {
         let largest_two = other.default_largest_unit();
         // 25. Let largestUnit be LargerOfTwoTemporalUnits(largestUnit1, largestUnit2).
         let largest_unit = largest_one.max(largest_two);
+
         // 26. Let norm1 be NormalizeTimeDuration(h1, min1, s1, ms1, mus1, ns1).
         let norm_one = NormalizedTimeDuration::from_time_duration(self.time());
         // 27. Let norm2 be NormalizeTimeDuration(h2, min2, s2, ms2, mus2, ns2).
         let norm_two = NormalizedTimeDuration::from_time_duration(other.time());
 
-        // 28. If IsCalendarUnit(largestUnit), throw a RangeError exception.
-        if largest_unit.is_calendar_unit() {
-            return Err(TemporalError::range().with_message(
-                "Largest unit cannot be a calendar unit when adding two durations.",
-            ));
+        match relative_to {
+            // 28. If zonedRelativeTo is undefined and plainRelativeTo is undefined, then
+            None => {
+                // a. If IsCalendarUnit(largestUnit), throw a RangeError exception.
+                if largest_unit.is_calendar_unit() {
+                    return Err(TemporalError::range()
+                        .with_message("A relativeTo is required to add calendar units."));
+                }
+                // b. Let normResult be ? AddNormalizedTimeDuration(norm1, norm2).
+                // c. Set normResult to ? Add24HourDaysToNormalizedTimeDuration(normResult, d1 + d2).
+                let result = (norm_one + norm_two)?
+                    .add_days((self.days().checked_add(&other.days())?).as_())?;
+
+                // d. Let result be ? BalanceTimeDuration(normResult, largestUnit).
+                let (result_days, result_time) =
+                    TimeDuration::from_normalized(result, largest_unit)?;
+
+                // e. Return ! CreateTemporalDuration(0, 0, 0, result.[[Days]], result.[[Hours]], result.[[Minutes]],
+                // result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]).
+                Ok(Duration::from_day_and_time(result_days, &result_time))
+            }
+            // 29. If plainRelativeTo is not undefined, then
+            Some(RelativeTo::PlainDate(plain_date)) => {
+                // a. Let dateDuration1 be ! CreateTemporalDuration(y1, mon1, w1, d1, 0, 0, 0, 0, 0, 0).
+                // b. Let dateDuration2 be ! CreateTemporalDuration(y2, mon2, w2, d2, 0, 0, 0, 0, 0, 0).
+                // c. Let intermediate be ? AddDate(calendarRec, plainRelativeTo, dateDuration1).
+                let intermediate = plain_date.add_date(&Duration::from(*self.date()), None)?;
+                // d. Let end be ? AddDate(calendarRec, intermediate, dateDuration2).
+                let end = intermediate.add_date(&Duration::from(*other.date()), None)?;
+                // e. Let dateLargestUnit be LargerOfTwoTemporalUnits("day", largestUnit).
+                // f-h. Let dateDifference be ? DifferenceDate(calendarRec, plainRelativeTo, end, differenceOptions).
+                let date_diff =
+                    plain_date.internal_diff_date(&end, largest_unit.max(TemporalUnit::Day))?;
+                // i. Let norm1WithDays be ? Add24HourDaysToNormalizedTimeDuration(norm1, dateDifference.[[Days]]).
+                let norm_one_with_days = norm_one.add_days(date_diff.days().as_())?;
+                // j. Set normResult to ? AddNormalizedTimeDuration(norm1WithDays, norm2).
+                let result = (norm_one_with_days + norm_two)?;
+                // k. Let result be ? BalanceTimeDuration(normResult, largestUnit).
+                // l. Return ! CreateTemporalDuration(dateDifference.[[Years]], dateDifference.[[Months]],
+                // dateDifference.[[Weeks]], result.[[Days]], result.[[Hours]], result.[[Minutes]],
+                // result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]).
+                Self::from_date_and_normalized(
+                    &DateDuration::new_unchecked(
+                        date_diff.years(),
+                        date_diff.months(),
+                        date_diff.weeks(),
+                        FiniteF64::default(),
+                    ),
+                    result,
+                    largest_unit,
+                )
+            }
+            // 30. Assert: zonedRelativeTo is not undefined.
+            Some(RelativeTo::ZonedDateTime(zdt)) => {
+                // 31. Let intermediateNs be ? AddZonedDateTime(zonedRelativeTo.[[Nanoseconds]], timeZoneRec,
+                // calendarRec, y1, mon1, w1, d1, norm1).
+                let intermediate = ZonedDateTime::new_unchecked(
+                    zdt.add_as_instant(self, ArithmeticOverflow::Constrain)?,
+                    zdt.calendar().clone(),
+                    zdt.tz().clone(),
+                );
+                // 32. Let endNs be ? AddZonedDateTime(intermediateNs, timeZoneRec, calendarRec, y2, mon2, w2, d2, norm2).
+                let end = intermediate.add_as_instant(other, ArithmeticOverflow::Constrain)?;
+                // 33. If largestUnit is not one of "year", "month", "week", or "day", then
+                // a. Let norm be NormalizedTimeDurationFromEpochNanosecondsDifference(endNs, zonedRelativeTo.[[Nanoseconds]]).
+                // b. Let result be BalanceTimeDuration(norm, largestUnit).
+                // c. Return ! CreateTemporalDuration(0, 0, 0, 0, result.[[Hours]], result.[[Minutes]],
+                // result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]).
+                // 34. Return ? DifferenceZonedDateTime(zonedRelativeTo.[[Nanoseconds]], endNs, timeZoneRec,
+                // calendarRec, largestUnit, OrdinaryObjectCreate(null)).
+                let diff = zdt.diff_instant(&end, largest_unit)?;
+                Self::from_date_and_normalized(
+                    &diff.date(),
+                    diff.normalized_time_duration(),
+                    largest_unit.min(TemporalUnit::Hour),
+                )
+            }
         }
-
-        // 29. Let normResult be ? AddNormalizedTimeDuration(norm1, norm2).
-        // 30. Set normResult to ? Add24HourDaysToNormalizedTimeDuration(normResult, d1 + d2).
-        let result =
-            (norm_one + norm_two)?.add_days((self.days().checked_add(&other.days())?).as_())?;
-
-        // 31. Let result be ? BalanceTimeDuration(normResult, largestUnit).
-        let (result_days, result_time) = TimeDuration::from_normalized(result, largest_unit)?;
-
-        // 32. Return ! CreateTemporalDuration(0, 0, 0, result.[[Days]], result.[[Hours]], result.[[Minutes]],
-        // result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]).
-        Ok(Duration::from_day_and_time(result_days, &result_time))
     }
 
     /// Returns the result of subtracting a `Duration` from the current `Duration`
+    ///
+    /// A `relative_to` is required when either duration contains years, months, or weeks.
     #[inline]
-    pub fn subtract(&self, other: &Self) -> TemporalResult<Self> {
-        self.add(&other.negated())
+    pub fn subtract(&self, other: &Self, relative_to: Option<RelativeTo>) -> TemporalResult<Self> {
+        self.add(&other.negated(), relative_to)
+    }
+
+    /// Balances the `Duration` so that its largest unit is `largest_unit`, e.g. `PT90M` with a
+    /// largest unit of `Hour` is `PT1H30M`.
+    ///
+    /// The total of the duration is preserved exactly and the result has a single sign. A
+    /// `relative_to` is required when the duration contains years, months, or weeks, or when
+    /// `largest_unit` is a calendar unit. Days are 24 hours unless `relative_to` is a
+    /// `ZonedDateTime`, in which case they are resolved in its time zone.
+    pub fn balance(
+        &self,
+        largest_unit: TemporalUnit,
+        relative_to: Option<RelativeTo>,
+    ) -> TemporalResult<Self> {
+        let largest_unit = match largest_unit {
+            TemporalUnit::Auto => self.default_largest_unit(),
+            unit => unit,
+        };
+
+        let norm = NormalizedTimeDuration::from_time_duration(self.time());
+        match relative_to {
+            None => {
+                let calendar_units_present =
+                    self.years() != 0.0 || self.months() != 0.0 || self.weeks() != 0.0;
+                if largest_unit.is_calendar_unit() || calendar_units_present {
+                    return Err(TemporalError::range()
+                        .with_message("A relativeTo is required to balance calendar units."));
+                }
+                let norm = norm.add_days(self.days().as_())?;
+                let (days, time) = TimeDuration::from_normalized(norm, largest_unit)?;
+                Ok(Duration::from_day_and_time(days, &time))
+            }
+            // Add the date portion to the anchor and take the date difference with the requested
+            // largest unit, as in `AddDurations`.
+            Some(RelativeTo::PlainDate(plain_date)) => {
+                let end = plain_date.add_date(&Duration::from(*self.date()), None)?;
+                let date_diff =
+                    plain_date.internal_diff_date(&end, largest_unit.max(TemporalUnit::Day))?;
+                let norm = norm.add_days(date_diff.days().as_())?;
+                Self::from_date_and_normalized(
+                    &DateDuration::new_unchecked(
+                        date_diff.years(),
+                        date_diff.months(),
+                        date_diff.weeks(),
+                        FiniteF64::default(),
+                    ),
+                    norm,
+                    largest_unit,
+                )
+            }
+            Some(RelativeTo::ZonedDateTime(zdt)) => {
+                let end = zdt.add_as_instant(self, ArithmeticOverflow::Constrain)?;
+                let diff = zdt.diff_instant(&end, largest_unit)?;
+                Self::from_date_and_normalized(
+                    &diff.date(),
+                    diff.normalized_time_duration(),
+                    largest_unit.min(TemporalUnit::Hour),
+                )
+            }
+        }
     }
 
     #[inline]
     pub fn round(
         &self,
         options: RoundingOptions,
-        relative_to: &RelativeTo,
+        relative_to: Option<RelativeTo>,
     ) -> TemporalResult<Self> {
         // NOTE: Steps 1-14 seem to be implementation specific steps.
         // 14. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
@@ -444,8 +740,12 @@ impl Duration {
         // 26. Let hoursToDaysConversionMayOccur be false.
         // 27. If duration.[[Days]] ≠ 0 and zonedRelativeTo is not undefined, set hoursToDaysConversionMayOccur to true.
         // 28. Else if abs(duration.[[Hours]]) ≥ 24, set hoursToDaysConversionMayOccur to true.
+        let plain_relative_to = relative_to.as_ref().and_then(RelativeTo::as_plain_date);
+        let zoned_relative_to = relative_to
+            .as_ref()
+            .and_then(RelativeTo::as_zoned_date_time);
         let hours_to_days_may_occur =
-            (self.days() != 0.0 && relative_to.zdt.is_some()) || self.hours().abs() >= 24.0;
+            (self.days() != 0.0 && zoned_relative_to.is_some()) || self.hours().abs() >= 24.0;
 
         // 29. If smallestUnit is "nanosecond" and roundingIncrement = 1, let roundingGranularityIsNoop
         // be true; else let roundingGranularityIsNoop be false.
@@ -465,9 +765,9 @@ impl Duration {
             && !hours_to_days_may_occur
             && self.minutes().abs() < 60.0
             && self.seconds().abs() < 60.0
-            && self.milliseconds() < 1000.0
-            && self.microseconds() < 1000.0
-            && self.nanoseconds() < 1000.0
+            && self.milliseconds().abs() < 1000.0
+            && self.microseconds().abs() < 1000.0
+            && self.nanoseconds().abs() < 1000.0
         {
             // a. NOTE: The above conditions mean that the operation will have no effect: the
             // smallest unit and rounding increment will leave the total duration unchanged,
@@ -480,29 +780,20 @@ impl Duration {
             return Ok(*self);
         }
 
-        // 32. Let precalculatedPlainDateTime be undefined.
-        // 33. If roundingGranularityIsNoop is false, or IsCalendarUnit(largestUnit) is true, or largestUnit is "day",
-        // or calendarUnitsPresent is true, or duration.[[Days]] ≠ 0, let plainDateTimeOrRelativeToWillBeUsed be true;
-        // else let plainDateTimeOrRelativeToWillBeUsed be false.
-        let pdtr_will_be_used = !is_noop
-            || resolved_options.largest_unit.is_calendar_unit()
-            || resolved_options.largest_unit == TemporalUnit::Day
-            || calendar_units_present
-            || self.days() == 0.0;
-
-        // 34. If zonedRelativeTo is not undefined and plainDateTimeOrRelativeToWillBeUsed is true, then
-        let _precalculated: Option<PlainDateTime> =
-            if relative_to.zdt.is_some() && pdtr_will_be_used {
-                return Err(TemporalError::general("Not yet implemented."));
-                // a. NOTE: The above conditions mean that the corresponding Temporal.PlainDateTime or
-                // Temporal.PlainDate for zonedRelativeTo will be used in one of the operations below.
-                // b. Let instant be ! CreateTemporalInstant(zonedRelativeTo.[[Nanoseconds]]).
-                // c. Set precalculatedPlainDateTime to ? GetPlainDateTimeFor(timeZoneRec, instant, zonedRelativeTo.[[Calendar]]).
-                // d. Set plainRelativeTo to ! CreateTemporalDate(precalculatedPlainDateTime.[[ISOYear]],
-                // precalculatedPlainDateTime.[[ISOMonth]], precalculatedPlainDateTime.[[ISODay]], zonedRelativeTo.[[Calendar]]).
-            } else {
-                None
-            };
+        // NOTE: Without calendar units, the result does not depend on `relativeTo` unless days
+        // are involved relative to a `ZonedDateTime`, where they may not be 24 hours long. In
+        // every other case, the time duration is rounded directly without the anchor.
+        if relative_to.is_some()
+            && !calendar_units_present
+            && !resolved_options.largest_unit.is_calendar_unit()
+            && (zoned_relative_to.is_none()
+                || (self.days() == 0.0 && resolved_options.largest_unit.is_time_unit()))
+        {
+            return self.round(options, None);
+        }
+
+        // 32-34. NOTE: The `PlainDateTime` of `zonedRelativeTo` is calculated where it is needed
+        // rather than precalculated.
         // 35. Let calendarRec be ? CreateCalendarMethodsRecordFromRelativeTo(plainRelativeTo, zonedRelativeTo, « DATE-ADD, DATE-UNTIL »).
 
         // 36. Let norm be NormalizeTimeDuration(duration.[[Hours]], duration.[[Minutes]], duration.[[Seconds]], duration.[[Milliseconds]],
@@ -511,17 +802,19 @@ impl Duration {
         // 37. Let emptyOptions be OrdinaryObjectCreate(null).
 
         // 38. If zonedRelativeTo is not undefined, then
-        let round_result = if let Some(_zdt) = relative_to.zdt {
+        let round_result = if let Some(zdt) = zoned_relative_to {
             // a. Let relativeEpochNs be zonedRelativeTo.[[Nanoseconds]].
             // b. Let relativeInstant be ! CreateTemporalInstant(relativeEpochNs).
             // c. Let targetEpochNs be ? AddZonedDateTime(relativeInstant, timeZoneRec, calendarRec, duration.[[Years]], duration.[[Months]], duration.[[Weeks]], duration.[[Days]], norm, precalculatedPlainDateTime).
+            let target = zdt.add_as_instant(self, ArithmeticOverflow::Constrain)?;
             // d. Let roundRecord be ? DifferenceZonedDateTimeWithRounding(relativeEpochNs, targetEpochNs, calendarRec, timeZoneRec, precalculatedPlainDateTime, emptyOptions, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+            let round_record = zdt.diff_zdt_with_rounding(&target, resolved_options)?;
             // e. Let roundResult be roundRecord.[[DurationRecord]].
-            return Err(TemporalError::general("Not yet implemented."));
+            round_record.0
         // 39. Else if plainRelativeTo is not undefined, then
-        } else if let Some(plain_date) = relative_to.date {
+        } else if let Some(plain_date) = plain_relative_to {
             // a. Let targetTime be AddTime(0, 0, 0, 0, 0, 0, norm).
-            let (balanced_days, time) = PlainTime::default().add_normalized_time_duration(norm);
+            let (balanced_days, time) = PlainTime::default().add_normalized_time_duration(norm)?;
             // b. Let dateDuration be ? CreateTemporalDuration(duration.[[Years]], duration.[[Months]], duration.[[Weeks]],
             // duration.[[Days]] + targetTime.[[Days]], 0, 0, 0, 0, 0, 0).
             let date_duration = DateDuration::new(
@@ -589,6 +882,9 @@ impl Duration {
 
 // TODO: Update, optimize, and fix the below. is_valid_duration should probably be generic over a T.
 
+const TWO_POWER_THIRTY_TWO: f64 = 4_294_967_296.0;
+const TWO_POWER_FIFTY_THREE: f64 = 9_007_199_254_740_992.0;
+
 // NOTE: Can FiniteF64 optimize the duration_validation
 /// Utility function to check whether the `Duration` fields are valid.
 #[inline]
@@ -634,15 +930,15 @@ pub(crate) fn is_valid_duration(
         }
     }
     // 3. If abs(years) ≥ 2**32, return false.
-    if years.abs() >= f64::from(u32::MAX) {
+    if years.abs() >= TWO_POWER_THIRTY_TWO {
         return false;
     };
     // 4. If abs(months) ≥ 2**32, return false.
-    if months.abs() >= f64::from(u32::MAX) {
+    if months.abs() >= TWO_POWER_THIRTY_TWO {
         return false;
     };
     // 5. If abs(weeks) ≥ 2**32, return false.
-    if weeks.abs() >= f64::from(u32::MAX) {
+    if weeks.abs() >= TWO_POWER_THIRTY_TWO {
         return false;
     };
 
@@ -660,15 +956,15 @@ pub(crate) fn is_valid_duration(
     );
     // Subseconds part
     let normalized_subseconds_parts = milliseconds.0.mul_add(
-        10e-3,
+        1e-3,
         microseconds
             .0
-            .mul_add(10e-6, nanoseconds.0.mul_add(10e-9, 0.0)),
+            .mul_add(1e-6, nanoseconds.0.mul_add(1e-9, 0.0)),
     );
 
     let normalized_seconds = normalized_seconds + normalized_subseconds_parts;
     // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
-    if normalized_seconds.abs() >= 2e53 {
+    if normalized_seconds.abs() >= TWO_POWER_FIFTY_THREE {
         return false;
     }
 
@@ -9,7 +9,7 @@ use crate::{primitive::FiniteF64, Sign, TemporalError, TemporalResult};
 /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-date-duration-records
 /// [field spec]: https://tc39.es/proposal-temporal/#sec-properties-of-temporal-duration-instances
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 pub struct DateDuration {
     /// `DateDuration`'s internal year value.
     pub years: FiniteF64,
@@ -48,7 +48,9 @@ impl DateDuration {
 }
 
 impl DateDuration {
-    /// Creates a new `DateDuration` with provided values.
+    /// Creates a new validated `DateDuration`.
+    ///
+    /// Returns a `RangeError` if the fields do not share a single sign, or are out of range.
     #[inline]
     pub fn new(
         years: FiniteF64,
@@ -1,12 +1,12 @@
 //! This module implements the normalized `Duration` records.
 
-use std::{num::NonZeroU128, ops::Add};
+use core::{num::NonZeroU128, ops::Add};
 
 use num_traits::{AsPrimitive, Euclid, FromPrimitive};
 
 use crate::{
     components::{tz::TimeZone, PlainDate, PlainDateTime},
-    iso::IsoDate,
+    iso::{IsoDate, IsoDateTime},
     options::{ResolvedRoundingOptions, TemporalRoundingMode, TemporalUnit},
     primitive::FiniteF64,
     rounding::{IncrementRounder, Round},
@@ -20,8 +20,8 @@ const MAX_TIME_DURATION: i128 = 9_007_199_254_740_991_999_999_999;
 // Nanoseconds constants
 
 const NS_PER_DAY_128BIT: i128 = NS_PER_DAY as i128;
-const NANOSECONDS_PER_MINUTE: f64 = 60.0 * 1e9;
-const NANOSECONDS_PER_HOUR: f64 = 60.0 * 60.0 * 1e9;
+const NS_PER_MINUTE_128BIT: i128 = 60_000_000_000;
+const NS_PER_HOUR_128BIT: i128 = 60 * NS_PER_MINUTE_128BIT;
 
 // ==== NormalizedTimeDuration ====
 //
@@ -38,13 +38,14 @@ pub struct NormalizedTimeDuration(pub(crate) i128);
 impl NormalizedTimeDuration {
     /// Equivalent: 7.5.20 NormalizeTimeDuration ( hours, minutes, seconds, milliseconds, microseconds, nanoseconds )
     pub(crate) fn from_time_duration(time: &TimeDuration) -> Self {
-        // TODO: Determine if there is a loss in precision from casting. If so, times by 1,000 (calculate in picoseconds) than truncate?
-        let mut nanoseconds: i128 = (time.hours.0 * NANOSECONDS_PER_HOUR) as i128;
-        nanoseconds += (time.minutes.0 * NANOSECONDS_PER_MINUTE) as i128;
-        nanoseconds += (time.seconds.0 * 1_000_000_000.0) as i128;
-        nanoseconds += (time.milliseconds.0 * 1_000_000.0) as i128;
-        nanoseconds += (time.microseconds.0 * 1_000.0) as i128;
-        nanoseconds += time.nanoseconds.0 as i128;
+        // NOTE: Each field is an integral value, so it is cast before scaling to keep the total
+        // exact in integer arithmetic.
+        let nanoseconds = time.hours.as_::<i128>() * NS_PER_HOUR_128BIT
+            + time.minutes.as_::<i128>() * NS_PER_MINUTE_128BIT
+            + time.seconds.as_::<i128>() * 1_000_000_000
+            + time.milliseconds.as_::<i128>() * 1_000_000
+            + time.microseconds.as_::<i128>() * 1_000
+            + time.nanoseconds.as_::<i128>();
         // NOTE(nekevss): Is it worth returning a `RangeError` below.
         debug_assert!(nanoseconds.abs() <= MAX_TIME_DURATION);
         Self(nanoseconds)
@@ -259,7 +260,10 @@ impl NormalizedDurationRecord {
     }
 
     pub(crate) fn sign(&self) -> TemporalResult<Sign> {
-        Ok(self.date.sign())
+        match self.date.sign() {
+            Sign::Zero => Ok(self.norm.sign()),
+            sign => Ok(sign),
+        }
     }
 }
 
@@ -488,29 +492,37 @@ impl NormalizedDurationRecord {
             None,
         )?;
 
-        // 7. If timeZoneRec is unset, then
-        let (start_epoch_ns, end_epoch_ns) = if tz.is_none() {
-            // TODO: Test valid range of EpochNanoseconds in order to add `expect` over `unwrap_or`
-            // a. Let startEpochNs be GetUTCEpochNanoseconds(start.[[Year]], start.[[Month]], start.[[Day]], start.[[Hour]], start.[[Minute]], start.[[Second]], start.[[Millisecond]], start.[[Microsecond]], start.[[Nanosecond]]).
-            // b. Let endEpochNs be GetUTCEpochNanoseconds(end.[[Year]], end.[[Month]], end.[[Day]], end.[[Hour]], end.[[Minute]], end.[[Second]], end.[[Millisecond]], end.[[Microsecond]], end.[[Nanosecond]]).
-            (
-                start.as_nanoseconds(0.0).unwrap_or(0),
-                end.as_nanoseconds(0.0).unwrap_or(0),
-            )
-        // 8. Else,
-        } else {
-            // a. Let startDateTime be ! CreateTemporalDateTime(start.[[Year]], start.[[Month]], start.[[Day]],
-            // start.[[Hour]], start.[[Minute]], start.[[Second]], start.[[Millisecond]], start.[[Microsecond]],
-            // start.[[Nanosecond]], calendarRec.[[Receiver]]).
-            // b. Let startInstant be ? GetInstantFor(timeZoneRec, startDateTime, "compatible").
-            // c. Let startEpochNs be startInstant.[[Nanoseconds]].
-            // d. Let endDateTime be ! CreateTemporalDateTime(end.[[Year]], end.[[Month]], end.[[Day]], end.[[Hour]], end.[[Minute]], end.[[Second]], end.[[Millisecond]], end.[[Microsecond]], end.[[Nanosecond]], calendarRec.[[Receiver]]).
-            // e. Let endInstant be ? GetInstantFor(timeZoneRec, endDateTime, "compatible").
-            // f. Let endEpochNs be endInstant.[[Nanoseconds]].
-            return Err(TemporalError::general(
-                "TimeZone handling not yet implemented.",
-            ));
-        };
+        let (start_epoch_ns, end_epoch_ns) =
+            match tz {
+                // 7. If timeZoneRec is unset, then
+                None => {
+                    // TODO: Test valid range of EpochNanoseconds in order to add `expect` over `unwrap_or`
+                    // a. Let startEpochNs be GetUTCEpochNanoseconds(start.[[Year]], start.[[Month]], start.[[Day]], start.[[Hour]], start.[[Minute]], start.[[Second]], start.[[Millisecond]], start.[[Microsecond]], start.[[Nanosecond]]).
+                    // b. Let endEpochNs be GetUTCEpochNanoseconds(end.[[Year]], end.[[Month]], end.[[Day]], end.[[Hour]], end.[[Minute]], end.[[Second]], end.[[Millisecond]], end.[[Microsecond]], end.[[Nanosecond]]).
+                    (
+                        start.as_nanoseconds(0.0).unwrap_or(0),
+                        end.as_nanoseconds(0.0).unwrap_or(0),
+                    )
+                }
+                // 8. Else,
+                Some(tz) => {
+                    // a. Let startDateTime be ! CreateTemporalDateTime(start.[[Year]], start.[[Month]], start.[[Day]],
+                    // start.[[Hour]], start.[[Minute]], start.[[Second]], start.[[Millisecond]], start.[[Microsecond]],
+                    // start.[[Nanosecond]], calendarRec.[[Receiver]]).
+                    // b. Let startInstant be ? GetInstantFor(timeZoneRec, startDateTime, "compatible").
+                    // c. Let startEpochNs be startInstant.[[Nanoseconds]].
+                    let start_instant = tz.get_epoch_nanoseconds_for(
+                        &PlainDateTime::new_unchecked(start, dt.calendar().clone()),
+                    )?;
+                    // d. Let endDateTime be ! CreateTemporalDateTime(end.[[Year]], end.[[Month]], end.[[Day]], end.[[Hour]], end.[[Minute]], end.[[Second]], end.[[Millisecond]], end.[[Microsecond]], end.[[Nanosecond]], calendarRec.[[Receiver]]).
+                    // e. Let endInstant be ? GetInstantFor(timeZoneRec, endDateTime, "compatible").
+                    // f. Let endEpochNs be endInstant.[[Nanoseconds]].
+                    let end_instant = tz.get_epoch_nanoseconds_for(
+                        &PlainDateTime::new_unchecked(end, dt.calendar().clone()),
+                    )?;
+                    (start_instant.epoch_nanos, end_instant.epoch_nanos)
+                }
+            };
 
         // 9. If endEpochNs = startEpochNs, throw a RangeError exception.
         if end_epoch_ns == start_epoch_ns {
@@ -581,10 +593,95 @@ impl NormalizedDurationRecord {
         }
     }
 
+    // NudgeToZonedTime ( sign, duration, dateTime, calendarRec, timeZoneRec, increment, unit, roundingMode )
     #[inline]
-    fn nudge_to_zoned_time(&self) -> TemporalResult<NudgeRecord> {
-        // TODO: Implement
-        Err(TemporalError::general("Not yet implemented."))
+    fn nudge_to_zoned_time(
+        &self,
+        sign: Sign,
+        dt: &PlainDateTime,
+        tz: &TimeZone,
+        options: ResolvedRoundingOptions,
+    ) -> TemporalResult<NudgeRecord> {
+        // 1. Let start be ? CalendarDateAdd(calendarRec, dateTime's date, duration.[[Date]], constrain).
+        // 2. Let startDateTime be CombineISODateAndTimeRecord(start, dateTime's time).
+        let start = dt.iso.add_date_duration(
+            dt.calendar().clone(),
+            &self.date(),
+            NormalizedTimeDuration::default(),
+            None,
+        )?;
+        // 3. Let endDate be BalanceISODate(start.[[Year]], start.[[Month]], start.[[Day]] + sign).
+        let end_date = IsoDate::balance(
+            start.date.year,
+            i32::from(start.date.month),
+            i32::from(start.date.day) + i32::from(sign.as_sign_multiplier()),
+        );
+        // 4. Let endDateTime be CombineISODateAndTimeRecord(endDate, dateTime's time).
+        let end = IsoDateTime::new_unchecked(end_date, start.time);
+        // 5. Let startEpochNs be ? GetEpochNanosecondsFor(timeZoneRec, startDateTime, compatible).
+        let start_epoch_ns = tz
+            .get_epoch_nanoseconds_for(&PlainDateTime::new_unchecked(start, dt.calendar().clone()))?
+            .epoch_nanos;
+        // 6. Let endEpochNs be ? GetEpochNanosecondsFor(timeZoneRec, endDateTime, compatible).
+        let end_epoch_ns = tz
+            .get_epoch_nanoseconds_for(&PlainDateTime::new_unchecked(end, dt.calendar().clone()))?
+            .epoch_nanos;
+        // 7. Let daySpan be NormalizedTimeDurationFromEpochNanosecondsDifference(endEpochNs, startEpochNs).
+        let day_span =
+            NormalizedTimeDuration::from_nanosecond_difference(end_epoch_ns, start_epoch_ns)?;
+        // 8. Assert: NormalizedTimeDurationSign(daySpan) = sign.
+        // 9. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 22 whose "Singular" column contains unit.
+        let unit_length = options.smallest_unit.as_nanoseconds().temporal_unwrap()?;
+        let increment = NonZeroU128::new(unit_length.into())
+            .and_then(|length| length.checked_mul(options.increment.as_extended_increment()))
+            .temporal_unwrap()?;
+        // 10. Let roundedNorm be ? RoundNormalizedTimeDurationToIncrement(duration.[[NormalizedTime]], increment × unitLength, roundingMode).
+        let rounded_norm = self
+            .normalized_time_duration()
+            .round_inner(increment, options.rounding_mode)?;
+        // 11. Let beyondDaySpan be ? SubtractNormalizedTimeDuration(roundedNorm, daySpan).
+        let beyond_day_span = rounded_norm.checked_sub(&day_span)?;
+        // 12. If NormalizedTimeDurationSign(beyondDaySpan) ≠ -sign, then
+        let (did_round_beyond_day, day_delta, rounded_norm, nudged_epoch_ns) =
+            if beyond_day_span.sign() != sign.negate() {
+                // a. Let didRoundBeyondDay be true.
+                // b. Let dayDelta be sign.
+                // c. Set roundedNorm to ? RoundNormalizedTimeDurationToIncrement(beyondDaySpan, increment × unitLength, roundingMode).
+                let rounded_norm = beyond_day_span.round_inner(increment, options.rounding_mode)?;
+                // d. Let nudgedEpochNs be AddNormalizedTimeDurationToEpochNanoseconds(roundedNorm, endEpochNs).
+                (
+                    true,
+                    sign.as_sign_multiplier(),
+                    rounded_norm,
+                    rounded_norm.0 + end_epoch_ns,
+                )
+            // 13. Else,
+            } else {
+                // a. Let didRoundBeyondDay be false.
+                // b. Let dayDelta be 0.
+                // c. Let nudgedEpochNs be AddNormalizedTimeDurationToEpochNanoseconds(roundedNorm, startEpochNs).
+                (false, 0, rounded_norm, rounded_norm.0 + start_epoch_ns)
+            };
+        // 14. Let resultDuration be ? CreateNormalizedDurationRecord(duration.[[Years]], duration.[[Months]],
+        // duration.[[Weeks]], duration.[[Days]] + dayDelta, roundedNorm).
+        let result_duration = NormalizedDurationRecord::new(
+            DateDuration::new(
+                self.date().years,
+                self.date().months,
+                self.date().weeks,
+                self.date().days.checked_add(&FiniteF64::from(day_delta))?,
+            )?,
+            rounded_norm,
+        )?;
+        // 15. Return Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs,
+        // [[DidExpandCalendarUnit]]: didRoundBeyondDay }.
+        // NOTE: A total is not computed when nudging a zoned time.
+        Ok(NudgeRecord {
+            normalized: result_duration,
+            total: None,
+            nudge_epoch_ns: nudged_epoch_ns,
+            expanded: did_round_beyond_day,
+        })
     }
 
     #[inline]
@@ -771,13 +868,17 @@ impl NormalizedDurationRecord {
             )?;
 
             // vi. If timeZoneRec is unset, then
-            let end_epoch_ns = if let Some(ref _tz) = tz {
+            let end_epoch_ns = if let Some(ref tz) = tz {
                 // 1. Let endDateTime be ! CreateTemporalDateTime(end.[[Year]], end.[[Month]], end.[[Day]],
                 // end.[[Hour]], end.[[Minute]], end.[[Second]], end.[[Millisecond]], end.[[Microsecond]],
                 // end.[[Nanosecond]], calendarRec.[[Receiver]]).
                 // 2. Let endInstant be ? GetInstantFor(timeZoneRec, endDateTime, "compatible").
                 // 3. Let endEpochNs be endInstant.[[Nanoseconds]].
-                return Err(TemporalError::general("Not yet implemented."));
+                tz.get_epoch_nanoseconds_for(&PlainDateTime::new_unchecked(
+                    end,
+                    date_time.calendar().clone(),
+                ))?
+                .epoch_nanos
             // vii. Else,
             } else {
                 // 1. Let endEpochNs be GetUTCEpochNanoseconds(end.[[Year]], end.[[Month]], end.[[Day]], end.[[Hour]],
@@ -819,16 +920,20 @@ impl NormalizedDurationRecord {
             || (tz.is_some() && options.smallest_unit == TemporalUnit::Day);
 
         // 4. If DurationSign(duration.[[Years]], duration.[[Months]], duration.[[Weeks]], duration.[[Days]], NormalizedTimeDurationSign(duration.[[NormalizedTime]]), 0, 0, 0, 0, 0) < 0, let sign be -1; else let sign be 1.
-        let sign = self.sign()?;
+        let sign = if self.sign()? == Sign::Negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
 
         // 5. If irregularLengthUnit is true, then
         let nudge_result = if irregular_unit {
             // a. Let nudgeResult be ? NudgeToCalendarUnit(sign, duration, destEpochNs, dateTime, calendarRec, timeZoneRec, increment, smallestUnit, roundingMode).
             self.nudge_calendar_unit(sign, dest_epoch_ns, dt, tz.clone(), options)?
         // 6. Else if timeZoneRec is not unset, then
-        } else if let Some(ref _tz) = tz {
+        } else if let Some(ref tz) = tz {
             // a. Let nudgeResult be ? NudgeToZonedTime(sign, duration, dateTime, calendarRec, timeZoneRec, increment, smallestUnit, roundingMode).
-            self.nudge_to_zoned_time()?
+            self.nudge_to_zoned_time(sign, dt, tz, options)?
         // 7. Else,
         } else {
             // a. Let nudgeResult be ? NudgeToDayOrTime(duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode).
@@ -1,5 +1,6 @@
 use crate::{
-    components::{calendar::Calendar, PlainDate},
+    components::{calendar::Calendar, tz::TimeZone, PlainDate, ZonedDateTime},
+    error::ErrorKind,
     options::{RoundingIncrement, TemporalRoundingMode},
 };
 
@@ -11,7 +12,7 @@ fn get_round_result(
     options: RoundingOptions,
 ) -> Vec<i32> {
     test_duration
-        .round(options, relative_to)
+        .round(options, Some(relative_to.clone()))
         .unwrap()
         .fields()
         .iter()
@@ -37,10 +38,7 @@ fn basic_positive_floor_rounding_v2() {
     .unwrap();
     let forward_date = PlainDate::new(2020, 4, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
 
-    let relative_forward = RelativeTo {
-        date: Some(&forward_date),
-        zdt: None,
-    };
+    let relative_forward = RelativeTo::PlainDate(forward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -109,10 +107,7 @@ fn basic_negative_floor_rounding_v2() {
     let backward_date =
         PlainDate::new(2020, 12, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
 
-    let relative_backward = RelativeTo {
-        date: Some(&backward_date),
-        zdt: None,
-    };
+    let relative_backward = RelativeTo::PlainDate(backward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -180,10 +175,7 @@ fn basic_positive_ceil_rounding() {
     .unwrap();
     let forward_date = PlainDate::new(2020, 4, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
 
-    let relative_forward = RelativeTo {
-        date: Some(&forward_date),
-        zdt: None,
-    };
+    let relative_forward = RelativeTo::PlainDate(forward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -250,10 +242,7 @@ fn basic_negative_ceil_rounding() {
     .unwrap();
     let backward_date =
         PlainDate::new(2020, 12, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
-    let relative_backward = RelativeTo {
-        date: Some(&backward_date),
-        zdt: None,
-    };
+    let relative_backward = RelativeTo::PlainDate(backward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -321,10 +310,7 @@ fn basic_positive_expand_rounding() {
     .unwrap();
     let forward_date = PlainDate::new(2020, 4, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
 
-    let relative_forward = RelativeTo {
-        date: Some(&forward_date),
-        zdt: None,
-    };
+    let relative_forward = RelativeTo::PlainDate(forward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -393,10 +379,7 @@ fn basic_negative_expand_rounding() {
     let backward_date =
         PlainDate::new(2020, 12, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
 
-    let relative_backward = RelativeTo {
-        date: Some(&backward_date),
-        zdt: None,
-    };
+    let relative_backward = RelativeTo::PlainDate(backward_date);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -459,10 +442,7 @@ fn rounding_increment_non_integer() {
         .unwrap(),
     );
     let binding = PlainDate::new(2000, 1, 1, Calendar::from_str("iso8601").unwrap()).unwrap();
-    let relative_to = RelativeTo {
-        date: Some(&binding),
-        zdt: None,
-    };
+    let relative_to = RelativeTo::PlainDate(binding);
 
     let mut options = RoundingOptions {
         largest_unit: None,
@@ -474,7 +454,9 @@ fn rounding_increment_non_integer() {
     let _ = options
         .increment
         .insert(RoundingIncrement::try_from(2.5).unwrap());
-    let result = test_duration.round(options, &relative_to).unwrap();
+    let result = test_duration
+        .round(options, Some(relative_to.clone()))
+        .unwrap();
 
     assert_eq!(
         result.fields(),
@@ -495,7 +477,9 @@ fn rounding_increment_non_integer() {
     let _ = options
         .increment
         .insert(RoundingIncrement::try_from(1e9 + 0.5).unwrap());
-    let result = test_duration.round(options, &relative_to).unwrap();
+    let result = test_duration
+        .round(options, Some(relative_to.clone()))
+        .unwrap();
     assert_eq!(
         result.fields(),
         &[
@@ -541,7 +525,7 @@ fn basic_add_duration() {
         FiniteF64::default(),
     )
     .unwrap();
-    let result = base.add(&other).unwrap();
+    let result = base.add(&other, None).unwrap();
     assert_eq!(result.days(), 3.0);
     assert_eq!(result.minutes(), 10.0);
 
@@ -558,7 +542,7 @@ fn basic_add_duration() {
         FiniteF64::default(),
     )
     .unwrap();
-    let result = base.add(&other).unwrap();
+    let result = base.add(&other, None).unwrap();
     assert_eq!(result.days(), -2.0);
     assert_eq!(result.minutes(), -10.0);
 }
@@ -591,7 +575,7 @@ fn basic_subtract_duration() {
         FiniteF64::default(),
     )
     .unwrap();
-    let result = base.subtract(&other).unwrap();
+    let result = base.subtract(&other, None).unwrap();
     assert_eq!(result.days(), 2.0);
     assert_eq!(result.minutes(), 10.0);
 
@@ -608,11 +592,152 @@ fn basic_subtract_duration() {
         FiniteF64::default(),
     )
     .unwrap();
-    let result = base.subtract(&other).unwrap();
+    let result = base.subtract(&other, None).unwrap();
     assert_eq!(result.days(), 6.0);
     assert_eq!(result.minutes(), 30.0);
 }
 
+#[test]
+fn add_durations_with_relative_to() {
+    let thirty = Duration::from_str("PT30M").unwrap();
+    let forty_five = Duration::from_str("PT45M").unwrap();
+    let result = thirty.add(&forty_five, None).unwrap();
+    assert_eq!(result.hours(), 1.0);
+    assert_eq!(result.minutes(), 15.0);
+
+    // Opposite durations cancel to a zero duration.
+    let result = thirty.subtract(&thirty, None).unwrap();
+    assert!(result.is_zero());
+    assert_eq!(result.sign(), Sign::Zero);
+
+    let month = Duration::from_str("P1M").unwrap();
+    let negative_day = Duration::from_str("-P1D").unwrap();
+    assert!(month.add(&negative_day, None).is_err());
+
+    let date = PlainDate::new(2020, 1, 31, Calendar::default()).unwrap();
+    let relative_to = RelativeTo::PlainDate(date.clone());
+    // 2020-01-31 + P1M is constrained to 2020-02-29, then -P1D lands on 2020-02-28.
+    let result = month.add(&negative_day, Some(relative_to)).unwrap();
+    assert_eq!(result.sign(), Sign::Positive);
+    assert_eq!(result.months(), 0.0);
+    assert_eq!(result.days(), 28.0);
+
+    let relative_to = RelativeTo::PlainDate(date.clone());
+    let result = month.subtract(&month, Some(relative_to)).unwrap();
+    assert!(result.is_zero());
+
+    // Time is added as a normalized duration rather than to a date-time, so the date and time
+    // parts of the result must agree in sign.
+    let relative_to = RelativeTo::PlainDate(date);
+    let negative_hour = Duration::from_str("-PT1H").unwrap();
+    assert!(month.add(&negative_hour, Some(relative_to)).is_err());
+}
+
+#[test]
+fn add_durations_with_zoned_relative_to() {
+    // 2020-01-31T12:00:00-05:00
+    let zdt = ZonedDateTime::new(
+        1_580_490_000_000_000_000,
+        Calendar::default(),
+        TimeZone {
+            iana: None,
+            offset: Some(-300),
+        },
+    )
+    .unwrap();
+    let relative_to = || Some(RelativeTo::ZonedDateTime(zdt.clone()));
+
+    // 2020-01-31 + P1M is constrained to 2020-02-29, then +P1D lands on 2020-03-01.
+    let month = Duration::from_str("P1M").unwrap();
+    let day = Duration::from_str("P1D").unwrap();
+    let result = month.add(&day, relative_to()).unwrap();
+    assert_eq!(result.months(), 1.0);
+    assert_eq!(result.days(), 1.0);
+
+    let hours = Duration::from_str("PT18H").unwrap();
+    let result = day.add(&hours, relative_to()).unwrap();
+    assert_eq!(result.days(), 1.0);
+    assert_eq!(result.hours(), 18.0);
+
+    let result = hours.add(&hours, relative_to()).unwrap();
+    assert_eq!(result.days(), 0.0);
+    assert_eq!(result.hours(), 36.0);
+
+    let result = month.balance(TemporalUnit::Day, relative_to()).unwrap();
+    assert_eq!(result.days(), 29.0);
+}
+
+#[test]
+fn duration_between_instants() {
+    let start = Instant::new(0).unwrap();
+    // 2 days, 3 hours, 4 minutes, and 5.000000006 seconds.
+    let end = Instant::new(183_845_000_000_006).unwrap();
+
+    let result = Duration::between_instants(&start, &end, DifferenceSettings::default()).unwrap();
+    assert_eq!(result.days(), 0.0);
+    assert_eq!(result.seconds(), 183_845.0);
+    assert_eq!(result.nanoseconds(), 6.0);
+
+    let settings = DifferenceSettings {
+        largest_unit: Some(TemporalUnit::Hour),
+        ..Default::default()
+    };
+    let result = Duration::between_instants(&start, &end, settings).unwrap();
+    assert_eq!(result.hours(), 51.0);
+    assert_eq!(result.minutes(), 4.0);
+
+    let settings = DifferenceSettings {
+        largest_unit: Some(TemporalUnit::Day),
+        ..Default::default()
+    };
+    let result = Duration::between_instants(&start, &end, settings).unwrap();
+    assert_eq!(result.days(), 2.0);
+    assert_eq!(result.hours(), 3.0);
+    let result = Duration::between_instants(&end, &start, settings).unwrap();
+    assert_eq!(result.days(), -2.0);
+    assert_eq!(result.hours(), -3.0);
+    assert_eq!(result.nanoseconds(), -6.0);
+
+    // The days are split exactly across the full range of instants.
+    let min = Instant::new(-8_640_000_000_000_000_000_000).unwrap();
+    let max = Instant::new(8_640_000_000_000_000_000_000).unwrap();
+    let result = Duration::between_instants(&min, &max, settings).unwrap();
+    assert_eq!(result.days(), 200_000_000.0);
+    assert_eq!(result.hours(), 0.0);
+    assert_eq!(result.nanoseconds(), 0.0);
+
+    for unit in [TemporalUnit::Week, TemporalUnit::Month, TemporalUnit::Year] {
+        let settings = DifferenceSettings {
+            largest_unit: Some(unit),
+            ..Default::default()
+        };
+        assert!(Duration::between_instants(&start, &end, settings).is_err());
+    }
+}
+
+#[test]
+fn duration_to_std() {
+    let one_and_a_half_hours = Duration::from_str("PT1.5H").unwrap();
+    assert_eq!(
+        one_and_a_half_hours.to_std().unwrap(),
+        core::time::Duration::from_secs(5_400)
+    );
+
+    let duration = Duration::from_str("P1DT1.000000001S").unwrap();
+    assert_eq!(
+        duration.to_std().unwrap(),
+        core::time::Duration::new(86_401, 1)
+    );
+    assert_eq!(
+        Duration::default().to_std().unwrap(),
+        core::time::Duration::ZERO
+    );
+
+    let negative = Duration::from_str("-PT1S").unwrap().to_std().unwrap_err();
+    let calendar = Duration::from_str("P1M").unwrap().to_std().unwrap_err();
+    assert_ne!(negative.message(), calendar.message());
+}
+
 #[test]
 fn partial_duration_empty() {
     let err = Duration::from_partial_duration(PartialDuration::default());
@@ -626,3 +751,539 @@ fn partial_duration_values() {
     let result = Duration::from_partial_duration(partial).unwrap();
     assert_eq!(result.years(), 20.0);
 }
+
+#[test]
+fn partial_duration_validation() {
+    let err = Duration::from_partial_duration(PartialDuration::default()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Type);
+
+    let partial = PartialDuration {
+        hours: Some(FiniteF64(2.0)),
+        minutes: Some(FiniteF64(-30.0)),
+        ..Default::default()
+    };
+    let err = Duration::from_partial_duration(partial).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Range);
+
+    let partial = PartialDuration {
+        hours: Some(FiniteF64(1.5)),
+        ..Default::default()
+    };
+    let err = Duration::from_partial_duration(partial).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Range);
+
+    let partial = PartialDuration {
+        hours: Some(FiniteF64(-2.0)),
+        minutes: Some(FiniteF64(-30.0)),
+        ..Default::default()
+    };
+    let result = Duration::from_partial_duration(partial).unwrap();
+    assert_eq!(result.days(), 0.0);
+    assert_eq!(result.hours(), -2.0);
+    assert_eq!(result.minutes(), -30.0);
+    assert_eq!(result.sign(), Sign::Negative);
+}
+
+#[test]
+fn time_duration_from_std_duration() {
+    let result = TimeDuration::try_from(std::time::Duration::new(90_061, 123_456_789)).unwrap();
+    assert_eq!(
+        result,
+        TimeDuration::new(
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(90_061.0),
+            FiniteF64(123.0),
+            FiniteF64(456.0),
+            FiniteF64(789.0),
+        )
+        .unwrap()
+    );
+
+    assert!(TimeDuration::try_from(std::time::Duration::from_secs(u64::MAX)).is_err());
+}
+
+#[test]
+fn duration_new_sign_validation() {
+    let new = |years: f64, days: f64, hours: f64| {
+        Duration::new(
+            FiniteF64(years),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(days),
+            FiniteF64(hours),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+        )
+    };
+
+    // P1Y-1D has mixed signs.
+    let err = new(1.0, -1.0, 0.0).unwrap_err();
+    assert_eq!(err.kind(), crate::error::ErrorKind::Range);
+    assert!(new(-1.0, 0.0, 1.0).is_err());
+
+    let positive = new(1.0, 1.0, 0.0).unwrap();
+    assert_eq!(positive.sign(), Sign::Positive);
+    assert!(!positive.is_zero());
+
+    let negative = new(-1.0, -1.0, -12.0).unwrap();
+    assert_eq!(negative.sign(), Sign::Negative);
+    assert!(!negative.is_zero());
+
+    let zero = new(0.0, 0.0, 0.0).unwrap();
+    assert_eq!(zero.sign(), Sign::Zero);
+    assert!(zero.is_zero());
+}
+
+#[test]
+fn duration_new_range_validation() {
+    let years = |years: f64| {
+        Duration::new(
+            FiniteF64(years),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+        )
+    };
+    assert!(years(4_294_967_295.0).is_ok());
+    assert!(years(4_294_967_296.0).is_err());
+
+    let seconds = |seconds: f64, milliseconds: f64| {
+        Duration::new(
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+            FiniteF64(seconds),
+            FiniteF64(milliseconds),
+            FiniteF64(0.0),
+            FiniteF64(0.0),
+        )
+    };
+    assert!(seconds(9_007_199_254_740_991.0, 0.0).is_ok());
+    assert!(seconds(9_007_199_254_740_992.0, 0.0).is_err());
+    assert!(seconds(9_007_199_254_740_990.0, 999.0).is_ok());
+}
+
+#[test]
+fn duration_compare_time_units() {
+    let hour = Duration::from_str("PT1H").unwrap();
+    let almost_hour = Duration::from_str("PT59M59S").unwrap();
+    assert_eq!(
+        Duration::compare(&hour, &almost_hour, None).unwrap(),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Duration::compare(&almost_hour, &hour, None).unwrap(),
+        Ordering::Less
+    );
+
+    // Without a `ZonedDateTime` anchor, days are always 24 hours.
+    let day = Duration::from_str("P1D").unwrap();
+    let hours = Duration::from_str("PT24H").unwrap();
+    assert_eq!(
+        Duration::compare(&day, &hours, None).unwrap(),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn duration_compare_calendar_units() {
+    let month = Duration::from_str("P1M").unwrap();
+    let days = Duration::from_str("P30D").unwrap();
+    assert!(Duration::compare(&month, &days, None).is_err());
+
+    let january = PlainDate::new(2024, 1, 1, Calendar::default()).unwrap();
+    let relative_to = RelativeTo::PlainDate(january);
+    assert_eq!(
+        Duration::compare(&month, &days, Some(relative_to)).unwrap(),
+        Ordering::Greater
+    );
+
+    let february = PlainDate::new(2024, 2, 1, Calendar::default()).unwrap();
+    let relative_to = RelativeTo::PlainDate(february);
+    assert_eq!(
+        Duration::compare(&month, &days, Some(relative_to)).unwrap(),
+        Ordering::Less
+    );
+}
+
+// TODO: Add a DST boundary case once IANA time zones are supported.
+#[test]
+fn duration_compare_zoned_anchor() {
+    // 2024-02-01T00:00:00-05:00
+    let zdt = ZonedDateTime::new(
+        1_706_763_600_000_000_000,
+        Calendar::default(),
+        TimeZone {
+            iana: None,
+            offset: Some(-300),
+        },
+    )
+    .unwrap();
+    let relative_to = || RelativeTo::ZonedDateTime(zdt.clone());
+
+    let day = Duration::from_str("P1D").unwrap();
+    let hours = Duration::from_str("PT24H").unwrap();
+    assert_eq!(
+        Duration::compare(&day, &hours, Some(relative_to())).unwrap(),
+        Ordering::Equal
+    );
+
+    let month = Duration::from_str("P1M").unwrap();
+    let days = Duration::from_str("P29DT1S").unwrap();
+    assert_eq!(
+        Duration::compare(&month, &days, Some(relative_to())).unwrap(),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn relative_to_from_str() {
+    let relative_to = RelativeTo::from_str("2024-02-01T12:00").unwrap();
+    assert_eq!(
+        relative_to.as_plain_date(),
+        Some(&PlainDate::new(2024, 2, 1, Calendar::default()).unwrap())
+    );
+    assert!(relative_to.zoned_date_time().is_err());
+
+    let relative_to = RelativeTo::from_str("2024-02-01T00:00-05:00[-05:00]").unwrap();
+    let expected = ZonedDateTime::new(
+        1_706_763_600_000_000_000,
+        Calendar::default(),
+        TimeZone {
+            iana: None,
+            offset: Some(-300),
+        },
+    )
+    .unwrap();
+    assert_eq!(relative_to.zoned_date_time().unwrap(), &expected);
+    assert!(relative_to.as_plain_date().is_none());
+
+    // The UTC offset must match the time zone annotation.
+    assert!(RelativeTo::from_str("2024-02-01T00:00+01:00[-05:00]").is_err());
+
+    // Days relative to a `ZonedDateTime` are resolved with exact time.
+    let month = Duration::from_str("P1M").unwrap();
+    let days = Duration::from_str("P29D").unwrap();
+    assert_eq!(
+        Duration::compare(&month, &days, Some(relative_to)).unwrap(),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn date_component_accessors() {
+    let duration = Duration::from_str("-P1Y2M3W4DT5H").unwrap();
+    assert_eq!(duration.years(), -1.0);
+    assert_eq!(duration.months(), -2.0);
+    assert_eq!(duration.weeks(), -3.0);
+    assert_eq!(duration.days().as_inner(), -4.0);
+    assert_eq!(duration.hours(), -5.0);
+
+    let date = duration.date();
+    assert_eq!(
+        [date.years, date.months, date.weeks, date.days],
+        [
+            duration.years(),
+            duration.months(),
+            duration.weeks(),
+            duration.days()
+        ]
+    );
+    assert!(duration.time().minutes.is_zero());
+}
+
+#[test]
+fn balance_duration() {
+    let duration = Duration::from_str("PT90M").unwrap();
+    let balanced = duration.balance(TemporalUnit::Hour, None).unwrap();
+    assert_eq!(balanced.hours(), 1.0);
+    assert_eq!(balanced.minutes(), 30.0);
+    assert_eq!(
+        balanced
+            .balance(TemporalUnit::Minute, None)
+            .unwrap()
+            .minutes(),
+        90.0
+    );
+
+    // Fields that exceed the range of their unit are carried into larger units.
+    let duration = Duration::from_str("-P1DT25H61M").unwrap();
+    let balanced = duration.balance(TemporalUnit::Auto, None).unwrap();
+    assert_eq!(balanced.days(), -2.0);
+    assert_eq!(balanced.hours(), -2.0);
+    assert_eq!(balanced.minutes(), -1.0);
+    let balanced = duration.balance(TemporalUnit::Second, None).unwrap();
+    assert_eq!(balanced.seconds(), -(49.0 * 3600.0 + 61.0 * 60.0));
+    assert_eq!(balanced.sign(), Sign::Negative);
+
+    let duration = Duration::from_str("P45D").unwrap();
+    assert!(duration.balance(TemporalUnit::Month, None).is_err());
+    let relative_to = RelativeTo::PlainDate(PlainDate::from_str("2024-01-01").unwrap());
+    let balanced = duration
+        .balance(TemporalUnit::Month, Some(relative_to.clone()))
+        .unwrap();
+    assert_eq!(balanced.months(), 1.0);
+    assert_eq!(balanced.days(), 14.0);
+
+    let duration = Duration::from_str("P1M").unwrap();
+    let balanced = duration
+        .balance(TemporalUnit::Day, Some(relative_to))
+        .unwrap();
+    assert_eq!(balanced.months(), 0.0);
+    assert_eq!(balanced.days(), 31.0);
+}
+
+#[test]
+fn time_duration_round() {
+    let duration = TimeDuration::new(
+        FiniteF64(1.0),
+        FiniteF64(30.0),
+        FiniteF64(45.0),
+        FiniteF64::default(),
+        FiniteF64::default(),
+        FiniteF64::default(),
+    )
+    .unwrap();
+    let options = |smallest_unit, largest_unit| RoundingOptions {
+        largest_unit,
+        smallest_unit: Some(smallest_unit),
+        increment: None,
+        rounding_mode: None,
+    };
+
+    // PT1H30M45S rounds half expand to PT1H31M.
+    let result = duration.round(options(TemporalUnit::Minute, None)).unwrap();
+    assert_eq!(result.hours, 1.0);
+    assert_eq!(result.minutes, 31.0);
+    assert_eq!(result.seconds, 0.0);
+
+    let result = duration
+        .round(options(TemporalUnit::Minute, Some(TemporalUnit::Minute)))
+        .unwrap();
+    assert_eq!(result.hours, 0.0);
+    assert_eq!(result.minutes, 91.0);
+
+    let result = duration
+        .negated()
+        .round(options(TemporalUnit::Hour, None))
+        .unwrap();
+    assert_eq!(result.hours, -2.0);
+    assert_eq!(result.minutes, 0.0);
+
+    // Calendar units require a relative anchor.
+    assert!(duration.round(options(TemporalUnit::Day, None)).is_err());
+    assert!(duration
+        .round(options(TemporalUnit::Minute, Some(TemporalUnit::Day)))
+        .is_err());
+
+    // The increment must evenly divide the next largest unit.
+    let mut invalid = options(TemporalUnit::Minute, None);
+    invalid.increment = Some(RoundingIncrement::try_new(7).unwrap());
+    assert!(duration.round(invalid).is_err());
+    invalid.increment = Some(RoundingIncrement::try_new(60).unwrap());
+    assert!(duration.round(invalid).is_err());
+    invalid.increment = Some(RoundingIncrement::try_new(15).unwrap());
+    let result = duration.round(invalid).unwrap();
+    assert_eq!(result.hours, 1.0);
+    assert_eq!(result.minutes, 30.0);
+}
+
+#[test]
+fn date_duration_sign_and_negation() {
+    let duration = DateDuration::new(
+        FiniteF64(1.0),
+        FiniteF64(2.0),
+        FiniteF64(0.0),
+        FiniteF64(3.0),
+    )
+    .unwrap();
+    assert_eq!(duration.sign(), Sign::Positive);
+    assert_eq!(duration.negated().sign(), Sign::Negative);
+    assert_eq!(duration.negated().years, -1.0);
+    assert_eq!(duration.negated().weeks, 0.0);
+    assert_eq!(duration.negated().abs(), duration);
+    assert_eq!(DateDuration::default().sign(), Sign::Zero);
+
+    assert!(DateDuration::new(
+        FiniteF64(1.0),
+        FiniteF64(-2.0),
+        FiniteF64(0.0),
+        FiniteF64(0.0)
+    )
+    .is_err());
+    let duration = Duration::from(duration);
+    assert_eq!(duration.months(), 2.0);
+    assert_eq!(duration.days(), 3.0);
+    assert!(duration.time().hours.is_zero());
+}
+
+#[test]
+fn round_time_duration_without_relative_to() {
+    let options = |smallest_unit| RoundingOptions {
+        largest_unit: None,
+        smallest_unit: Some(smallest_unit),
+        increment: None,
+        rounding_mode: None,
+    };
+    let assert_time = |duration: Duration, expected: (f64, f64, f64, f64)| {
+        assert_eq!(duration.hours(), expected.0);
+        assert_eq!(duration.minutes(), expected.1);
+        assert_eq!(duration.seconds(), expected.2);
+        assert_eq!(duration.milliseconds(), expected.3);
+    };
+
+    let duration = Duration::from_str("PT1H23M45.678S").unwrap();
+    let result = duration.round(options(TemporalUnit::Second), None).unwrap();
+    assert_time(result, (1.0, 23.0, 46.0, 0.0));
+
+    // Rounding up the seconds carries into the minutes and hours.
+    let duration = Duration::from_str("PT1H59M59.7S").unwrap();
+    let result = duration.round(options(TemporalUnit::Second), None).unwrap();
+    assert_time(result, (2.0, 0.0, 0.0, 0.0));
+    let result = duration
+        .negated()
+        .round(options(TemporalUnit::Second), None)
+        .unwrap();
+    assert_time(result, (-2.0, 0.0, 0.0, 0.0));
+
+    // Unbalanced negative milliseconds are balanced even without a rounding granularity.
+    let duration = Duration::new(
+        FiniteF64::default(),
+        FiniteF64::default(),
+        FiniteF64::default(),
+        FiniteF64::default(),
+        FiniteF64::default(),
+        FiniteF64(-1.0),
+        FiniteF64::default(),
+        FiniteF64(-1500.0),
+        FiniteF64::default(),
+        FiniteF64::default(),
+    )
+    .unwrap();
+    let result = duration
+        .round(options(TemporalUnit::Nanosecond), None)
+        .unwrap();
+    assert_time(result, (0.0, -1.0, -1.0, -500.0));
+
+    // A time duration does not need the anchor, even relative to a `ZonedDateTime`.
+    let duration = Duration::from_str("PT1H23M45.678S").unwrap();
+    let zdt = ZonedDateTime::new(
+        1_706_763_600_000_000_000,
+        Calendar::default(),
+        TimeZone {
+            iana: None,
+            offset: Some(-300),
+        },
+    )
+    .unwrap();
+    let result = duration
+        .round(
+            options(TemporalUnit::Second),
+            Some(RelativeTo::ZonedDateTime(zdt)),
+        )
+        .unwrap();
+    assert_time(result, (1.0, 23.0, 46.0, 0.0));
+
+    let date = PlainDate::new(2020, 1, 31, Calendar::default()).unwrap();
+    let result = duration
+        .round(
+            options(TemporalUnit::Minute),
+            Some(RelativeTo::PlainDate(date)),
+        )
+        .unwrap();
+    assert_time(result, (1.0, 24.0, 0.0, 0.0));
+
+    // Calendar units still require an anchor.
+    let duration = Duration::from_str("P1MT59.7S").unwrap();
+    assert!(duration.round(options(TemporalUnit::Second), None).is_err());
+}
+
+// TODO: Add a DST boundary case once IANA time zones are supported.
+#[test]
+fn round_relative_to_zoned_date_time() {
+    let options = |smallest_unit| RoundingOptions {
+        largest_unit: None,
+        smallest_unit: Some(smallest_unit),
+        increment: None,
+        rounding_mode: None,
+    };
+    // 2020-01-31T12:00:00-05:00
+    let zdt = ZonedDateTime::new(
+        1_580_490_000_000_000_000,
+        Calendar::default(),
+        TimeZone {
+            iana: None,
+            offset: Some(-300),
+        },
+    )
+    .unwrap();
+    let relative_to = || Some(RelativeTo::ZonedDateTime(zdt.clone()));
+
+    // 2020-03-16 is 16 days into the 31 days from 2020-02-29 to 2020-03-31.
+    let duration = Duration::from_str("P1M16D").unwrap();
+    let result = duration
+        .round(options(TemporalUnit::Month), relative_to())
+        .unwrap();
+    assert_eq!(result.months(), 2.0);
+    assert_eq!(result.days(), 0.0);
+
+    let duration = Duration::from_str("P1DT11H59M").unwrap();
+    let result = duration
+        .round(options(TemporalUnit::Hour), relative_to())
+        .unwrap();
+    assert_eq!(result.days(), 1.0);
+    assert_eq!(result.hours(), 12.0);
+    assert_eq!(result.minutes(), 0.0);
+
+    let duration = Duration::from_str("PT23H30M").unwrap();
+    let result = duration
+        .round(options(TemporalUnit::Day), relative_to())
+        .unwrap();
+    assert_eq!(result.days(), 1.0);
+    assert_eq!(result.hours(), 0.0);
+}
+
+#[test]
+fn from_normalized_safe_integer_range() {
+    // The difference between the earliest and latest instants.
+    let norm = NormalizedTimeDuration(17_280_000_000_000_000_000_000);
+
+    let (days, time) = TimeDuration::from_normalized(norm, TemporalUnit::Day).unwrap();
+    assert_eq!(days, 200_000_000.0);
+    assert!(time.hours.is_zero());
+    let (_, time) = TimeDuration::from_normalized(norm, TemporalUnit::Second).unwrap();
+    assert_eq!(time.seconds, 17_280_000_000_000.0);
+    let (_, time) = TimeDuration::from_normalized(
+        NormalizedTimeDuration(-17_280_000_000_000_000_000_000),
+        TemporalUnit::Hour,
+    )
+    .unwrap();
+    assert_eq!(time.hours, -4_800_000_000.0);
+
+    // Milliseconds and nanoseconds would exceed 2^53 - 1 and lose precision as an `f64`.
+    for unit in [TemporalUnit::Millisecond, TemporalUnit::Nanosecond] {
+        let err = TimeDuration::from_normalized(norm, unit).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+    }
+
+    // The largest safe integer is still exact.
+    let (_, time) = TimeDuration::from_normalized(
+        NormalizedTimeDuration(9_007_199_254_740_991),
+        TemporalUnit::Nanosecond,
+    )
+    .unwrap();
+    assert_eq!(time.nanoseconds, 9_007_199_254_740_991.0);
+}
@@ -1,13 +1,18 @@
 //! An implementation of `TimeDuration` and it's methods.
 
 use crate::{
-    options::TemporalUnit, primitive::FiniteF64, temporal_assert, TemporalError, TemporalResult,
+    options::{ResolvedRoundingOptions, RoundingOptions, TemporalUnit},
+    primitive::FiniteF64,
+    temporal_assert, TemporalError, TemporalResult,
 };
 
-use super::{is_valid_duration, normalized::NormalizedTimeDuration};
+use super::{is_valid_duration, normalized::NormalizedTimeDuration, Duration};
 
 use num_traits::Euclid;
 
+/// The largest integer that an `f64` can represent exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: i128 = 9_007_199_254_740_991;
+
 /// `TimeDuration` represents the [Time Duration record][spec] of the `Duration.`
 ///
 /// These fields are laid out in the [Temporal Proposal][field spec] as 64-bit floating point numbers.
@@ -58,8 +63,13 @@ impl TimeDuration {
     ///
     /// Equivalent: `BalanceTimeDuration`
     ///
+    /// NOTE: Step 11 of the specification allows `milliseconds`, `microseconds` and `nanoseconds`
+    /// to be unsafe integers, e.g. 31,536,000,000,000,000 nanoseconds for a year. Rather than
+    /// storing such a field as a rounded `f64`, this returns a `RangeError`.
+    ///
     /// # Errors:
     ///   - Will error if provided duration is invalid
+    ///   - Will error if a balanced field exceeds the safe integer range of an `f64`
     pub(crate) fn from_normalized(
         norm: NormalizedTimeDuration,
         largest_unit: TemporalUnit,
@@ -186,6 +196,23 @@ impl TimeDuration {
         // using floating point arithmetic. It can be implemented in C++ using std::fma(). String manipulation will also
         // give an exact result, since the multiplication is by a power of 10.
 
+        // NOTE: An `f64` field can only represent integers up to 2^53 - 1 exactly, so a field that
+        // would silently lose precision is a `RangeError` instead.
+        let fields = [
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        ];
+        if fields.iter().any(|field| *field > MAX_SAFE_INTEGER) {
+            return Err(TemporalError::range().with_message(format!(
+                "Balancing the time duration to \"{largest_unit}\" exceeds the safe integer range."
+            )));
+        }
+
         // NOTE: days may have the potentially to exceed i64
         // 12. Return ! CreateTimeDurationRecord(days × sign, hours × sign, minutes × sign, seconds × sign, milliseconds × sign, microseconds × sign, nanoseconds × sign).
         let days = FiniteF64::try_from(days as f64)?.copysign(sign.into());
@@ -305,6 +332,27 @@ impl TimeDuration {
         }
     }
 
+    /// Rounds the current `TimeDuration` according to the provided options.
+    ///
+    /// A `TimeDuration` has no relative anchor, so both the smallest and largest units must be
+    /// time units. An unset largest unit defaults to the largest non-zero unit of the duration,
+    /// or the smallest unit if that is larger.
+    pub fn round(&self, options: RoundingOptions) -> TemporalResult<Self> {
+        let resolved = ResolvedRoundingOptions::from_options(
+            options,
+            TemporalUnit::Nanosecond,
+            Duration::from(*self).default_largest_unit(),
+        )?;
+        if !resolved.smallest_unit.is_time_unit() || !resolved.largest_unit.is_time_unit() {
+            return Err(TemporalError::range()
+                .with_message("A TimeDuration can only be rounded to a time unit."));
+        }
+
+        let (record, _) = self.to_normalized().round(FiniteF64::default(), resolved)?;
+        Self::from_normalized(record.normalized_time_duration(), resolved.largest_unit)
+            .map(|(_, duration)| duration)
+    }
+
     /// Utility function for returning if values in a valid range.
     #[inline]
     #[must_use]
@@ -317,3 +365,25 @@ impl TimeDuration {
             && self.milliseconds.abs() < 1000f64
     }
 }
+
+// ==== Trait impls ====
+
+/// Converts a `std::time::Duration` into a `TimeDuration` with the duration's whole seconds in the
+/// `seconds` field, and its subsecond nanoseconds split across the subsecond fields.
+///
+/// The conversion is fallible as a `std::time::Duration` may exceed the maximum time duration.
+impl TryFrom<core::time::Duration> for TimeDuration {
+    type Error = TemporalError;
+
+    fn try_from(value: core::time::Duration) -> Result<Self, Self::Error> {
+        let subsecond_nanos = value.subsec_nanos();
+        Self::new(
+            FiniteF64::default(),
+            FiniteF64::default(),
+            FiniteF64::try_from(i128::from(value.as_secs()))?,
+            FiniteF64::from(subsecond_nanos / 1_000_000),
+            FiniteF64::from(subsecond_nanos / 1_000 % 1_000),
+            FiniteF64::from(subsecond_nanos % 1_000),
+        )
+    }
+}
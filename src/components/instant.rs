@@ -1,21 +1,25 @@
 //! An implementation of the Temporal Instant.
 
-use std::{num::NonZeroU128, str::FromStr};
+use core::{num::NonZeroU128, str::FromStr};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::{
-    components::{duration::TimeDuration, Duration},
-    iso::{IsoDate, IsoDateTime, IsoTime},
+    components::{duration::TimeDuration, Duration, PlainTime},
+    iso::{IsoDate, IsoTime},
     options::{
-        ArithmeticOverflow, DifferenceOperation, DifferenceSettings, ResolvedRoundingOptions,
-        RoundingOptions, TemporalUnit,
+        ArithmeticOverflow, DifferenceOperation, DifferenceSettings, Precision,
+        ResolvedRoundingOptions, RoundingOptions, TemporalUnit, ToStringRoundingOptions,
     },
-    parsers::parse_instant,
+    parsers::{check_rfc3339_date_time, parse_instant, utc_offset_nanoseconds},
     primitive::FiniteF64,
     rounding::{IncrementRounder, Round},
-    Sign, TemporalError, TemporalResult, TemporalUnwrap,
+    utils::{format_fractional_seconds, pad_iso_year, validate_offset_nanoseconds},
+    Sign, TemporalError, TemporalResult, TemporalUnwrap, NS_PER_DAY,
 };
 
-use num_traits::{Euclid, FromPrimitive, ToPrimitive};
+use num_bigint::BigInt;
+use num_traits::{Euclid, ToPrimitive};
 
 use super::duration::normalized::NormalizedTimeDuration;
 
@@ -25,7 +29,7 @@ const NANOSECONDS_PER_HOUR: f64 = 60f64 * NANOSECONDS_PER_MINUTE;
 
 /// The native Rust implementation of `Temporal.Instant`
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Instant {
     pub(crate) epoch_nanos: i128,
 }
@@ -33,22 +37,32 @@ pub struct Instant {
 // ==== Private API ====
 
 impl Instant {
-    // TODO: Update to `i128`?
     /// Adds a `TimeDuration` to the current `Instant`.
     ///
     /// Temporal-Proposal equivalent: `AddDurationToOrSubtractDurationFrom`.
     pub(crate) fn add_to_instant(&self, duration: &TimeDuration) -> TemporalResult<Self> {
-        let result = self.epoch_nanoseconds()
-            + duration.nanoseconds.0
-            + (duration.microseconds.0 * 1000f64)
-            + (duration.milliseconds.0 * 1_000_000f64)
-            + (duration.seconds.0 * NANOSECONDS_PER_SECOND)
-            + (duration.minutes.0 * NANOSECONDS_PER_MINUTE)
-            + (duration.hours.0 * NANOSECONDS_PER_HOUR);
-        let nanos = i128::from_f64(result).ok_or_else(|| {
-            TemporalError::range().with_message("Duration added to instant exceeded valid range.")
-        })?;
-        Self::new(nanos)
+        // NOTE: A normalized time duration is at most ~9 * 10^24 nanoseconds, so the sum is
+        // exact and can not overflow an `i128`.
+        let norm = NormalizedTimeDuration::from_time_duration(duration);
+        Self::from_arithmetic_result(self.epoch_nanos + norm.0)
+    }
+
+    /// Creates an `Instant` from the epoch nanoseconds resulting from arithmetic, reporting
+    /// which limit was exceeded and by how much if they are out of range.
+    fn from_arithmetic_result(epoch_nanos: i128) -> TemporalResult<Self> {
+        if epoch_nanos > crate::NS_MAX_INSTANT {
+            return Err(TemporalError::range().with_message(format!(
+                "Instant arithmetic exceeded the maximum instant by {}ns.",
+                epoch_nanos - crate::NS_MAX_INSTANT
+            )));
+        }
+        if epoch_nanos < crate::NS_MIN_INSTANT {
+            return Err(TemporalError::range().with_message(format!(
+                "Instant arithmetic exceeded the minimum instant by {}ns.",
+                crate::NS_MIN_INSTANT - epoch_nanos
+            )));
+        }
+        Ok(Self { epoch_nanos })
     }
 
     // TODO: Add test for `diff_instant`.
@@ -72,6 +86,14 @@ impl Instant {
             TemporalUnit::Second,
             TemporalUnit::Nanosecond,
         )?;
+        // NOTE: `GetDifferenceSettings` only permits time units, as an `Instant` has no calendar.
+        if !resolved_options.largest_unit.is_time_unit()
+            || !resolved_options.smallest_unit.is_time_unit()
+        {
+            return Err(TemporalError::range().with_message(
+                "Instant differences only support time units, as an Instant has no calendar.",
+            ));
+        }
 
         // Below are the steps from Difference Instant.
         // 5. Let diffRecord be DifferenceInstant(instant.[[Nanoseconds]], other.[[Nanoseconds]],
@@ -127,10 +149,17 @@ impl Instant {
             return Err(TemporalError::range().with_message("Increment exceeded a valid range."));
         };
 
-        let rounded = IncrementRounder::<i128>::from_positive_parts(self.epoch_nanos, increment)?
+        // NOTE: Instants are rounded as if positive. All valid increments evenly divide a day, and
+        // therefore `NS_MIN_INSTANT`, so the epoch nanoseconds are offset to be non-negative and
+        // rounded with exact integer arithmetic.
+        let offset_nanos = self.epoch_nanos - crate::NS_MIN_INSTANT;
+        let rounded = IncrementRounder::<i128>::from_positive_parts(offset_nanos, increment)?
             .round_as_positive(resolved_options.rounding_mode);
 
-        Ok(rounded.into())
+        i128::try_from(rounded)
+            .ok()
+            .and_then(|rounded| rounded.checked_add(crate::NS_MIN_INSTANT))
+            .temporal_unwrap()
     }
 
     /// Utility for converting `Instant` to f64.
@@ -148,6 +177,19 @@ impl Instant {
 // ==== Public API ====
 
 impl Instant {
+    /// The `Instant` of the Unix epoch, `1970-01-01T00:00:00Z`.
+    pub const UNIX_EPOCH: Self = Self { epoch_nanos: 0 };
+
+    /// The earliest representable `Instant`, `-271821-04-20T00:00:00Z`.
+    pub const MIN: Self = Self {
+        epoch_nanos: crate::NS_MIN_INSTANT,
+    };
+
+    /// The latest representable `Instant`, `+275760-09-13T00:00:00Z`.
+    pub const MAX: Self = Self {
+        epoch_nanos: crate::NS_MAX_INSTANT,
+    };
+
     /// Create a new validated `Instant`.
     #[inline]
     pub fn new(epoch_nanoseconds: i128) -> TemporalResult<Self> {
@@ -205,6 +247,31 @@ impl Instant {
         self.add_to_instant(&duration.negated())
     }
 
+    /// Adds a `core::time::Duration` to the current `Instant` using exact integer arithmetic.
+    ///
+    /// Returns a `RangeError` if the result is outside the valid epoch range.
+    pub fn add_std(&self, duration: core::time::Duration) -> TemporalResult<Self> {
+        let nanos = std_duration_nanos(duration)?;
+        Self::from_arithmetic_result(
+            self.epoch_nanos
+                .checked_add(nanos)
+                .ok_or_else(instant_range_error)?,
+        )
+    }
+
+    /// Subtracts a `core::time::Duration` from the current `Instant` using exact integer
+    /// arithmetic.
+    ///
+    /// Returns a `RangeError` if the result is outside the valid epoch range.
+    pub fn subtract_std(&self, duration: core::time::Duration) -> TemporalResult<Self> {
+        let nanos = std_duration_nanos(duration)?;
+        Self::from_arithmetic_result(
+            self.epoch_nanos
+                .checked_sub(nanos)
+                .ok_or_else(instant_range_error)?,
+        )
+    }
+
     /// Returns a `TimeDuration` representing the duration since provided `Instant`
     #[inline]
     pub fn since(
@@ -225,7 +292,32 @@ impl Instant {
         self.diff_instant(DifferenceOperation::Until, other, settings)
     }
 
+    /// Returns the exact number of nanoseconds from this `Instant` until `other`, which is
+    /// negative when `other` is earlier.
+    ///
+    /// Unlike `until`, the difference is neither rounded nor balanced. The difference between any
+    /// two valid `Instant`s fits in an `i128`, so the result is always exact.
+    #[inline]
+    #[must_use]
+    pub fn nanoseconds_until(&self, other: &Self) -> i128 {
+        other.epoch_nanos - self.epoch_nanos
+    }
+
+    /// Returns whether this `Instant` and `other` are the exact same point in time.
+    ///
+    /// This is equivalent to `==`, and compares the exact epoch nanoseconds. Note that the `f64`
+    /// getters such as `epoch_nanoseconds` and `epoch_milliseconds` are lossy, so `Instant`s that
+    /// are not equal may still have equal `f64` values, e.g. two `Instant`s a nanosecond apart.
+    #[inline]
+    #[must_use]
+    pub fn equals(&self, other: &Self) -> bool {
+        self.epoch_nanos == other.epoch_nanos
+    }
+
     /// Returns an `Instant` by rounding the current `Instant` according to the provided settings.
+    ///
+    /// The increment must evenly divide a day, and may be an entire day, e.g. 24 hours or
+    /// 86 400 seconds, which rounds to midnight UTC.
     pub fn round(&self, options: RoundingOptions) -> TemporalResult<Self> {
         let resolved_options = ResolvedRoundingOptions::from_instant_options(options)?;
 
@@ -233,10 +325,42 @@ impl Instant {
         Self::new(round_result)
     }
 
+    /// Rounds the current `Instant` according to the provided settings, returning the exact
+    /// rounded epoch nanoseconds.
+    ///
+    /// Every valid increment evenly divides a day, so the result is always within the valid
+    /// range of an `Instant` and does not need to be validated again.
+    pub fn round_to_bigint(&self, options: RoundingOptions) -> TemporalResult<BigInt> {
+        let resolved_options = ResolvedRoundingOptions::from_instant_options(options)?;
+        self.round_instant(resolved_options).map(BigInt::from)
+    }
+
+    /// Returns the ISO wall-clock time of this `Instant` at a fixed offset, dropping the date.
+    ///
+    /// The offset must be less than a day, i.e. within `-1439..=1439` minutes. The time is
+    /// calculated from the exact nanoseconds of the local day, so every nanosecond is preserved.
+    pub fn to_plain_time_iso(&self, offset_minutes: i32) -> TemporalResult<PlainTime> {
+        let local_nanos = self.epoch_nanos + validate_offset_nanoseconds(offset_minutes)?;
+        // NOTE: The euclidean remainder is always within `0..NS_PER_DAY`.
+        let nanos_of_day = local_nanos.rem_euclid(i128::from(NS_PER_DAY)) as u64;
+        PlainTime::from_nanoseconds_since_midnight(nanos_of_day)
+    }
+
+    /// Parses a strict RFC 3339 string, e.g. `2024-05-04T17:05:24.123456789Z`, into an `Instant`.
+    ///
+    /// A `Z` or a numeric `±HH:MM` UTC offset is required, and IXDTF extensions such as
+    /// annotations, expanded years, or the basic format are rejected. Fractional seconds beyond
+    /// nanosecond precision are truncated.
+    pub fn from_rfc3339_str(s: &str) -> TemporalResult<Self> {
+        check_rfc3339_date_time(s)?;
+        Self::from_str(s)
+    }
+
     /// Returns the `epochSeconds` value for this `Instant`.
     #[must_use]
     pub fn epoch_seconds(&self) -> f64 {
-        (&self.epoch_nanos / 1_000_000_000)
+        self.epoch_nanos
+            .div_euclid(1_000_000_000)
             .to_f64()
             .expect("A validated Instant should be within a valid f64")
             .floor()
@@ -245,7 +369,8 @@ impl Instant {
     /// Returns the `epochMilliseconds` value for this `Instant`.
     #[must_use]
     pub fn epoch_milliseconds(&self) -> f64 {
-        (&self.epoch_nanos / 1_000_000)
+        self.epoch_nanos
+            .div_euclid(1_000_000)
             .to_f64()
             .expect("A validated Instant should be within a valid f64")
             .floor()
@@ -254,21 +379,170 @@ impl Instant {
     /// Returns the `epochMicroseconds` value for this `Instant`.
     #[must_use]
     pub fn epoch_microseconds(&self) -> f64 {
-        (&self.epoch_nanos / 1_000)
+        self.epoch_nanos
+            .div_euclid(1_000)
             .to_f64()
             .expect("A validated Instant should be within a valid f64")
             .floor()
     }
 
+    /// Returns the exact `epochSeconds` value for this `Instant`.
+    ///
+    /// Like JavaScript's `epochSeconds`, the value is floored, so instants before the epoch round
+    /// towards negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn epoch_seconds_i64(&self) -> i64 {
+        // NOTE: A valid `Instant` is within ±8.64 × 10^12 seconds.
+        self.epoch_nanos.div_euclid(1_000_000_000) as i64
+    }
+
+    /// Returns the exact `epochMilliseconds` value for this `Instant`.
+    ///
+    /// Like JavaScript's `epochMilliseconds`, the value is floored, so instants before the epoch
+    /// round towards negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn epoch_milliseconds_i64(&self) -> i64 {
+        // NOTE: A valid `Instant` is within ±8.64 × 10^15 milliseconds.
+        self.epoch_nanos.div_euclid(1_000_000) as i64
+    }
+
+    /// Returns the exact `epochMicroseconds` value for this `Instant`, floored like the other
+    /// epoch getters.
+    #[inline]
+    #[must_use]
+    pub fn epoch_microseconds_i128(&self) -> i128 {
+        self.epoch_nanos.div_euclid(1_000)
+    }
+
     /// Returns the `epochNanoseconds` value for this `Instant`.
     #[must_use]
     pub fn epoch_nanoseconds(&self) -> f64 {
         self.to_f64()
     }
+
+    /// Returns the IXDTF string for this `Instant` in UTC, e.g. `2019-10-29T10:46:38.271986289Z`.
+    ///
+    /// Trailing zeros of the fractional seconds are omitted. The epoch nanoseconds are decomposed
+    /// with exact integer arithmetic, so every nanosecond digit is preserved across the entire
+    /// valid range of an `Instant`.
+    #[must_use]
+    pub fn to_ixdtf_string(&self) -> String {
+        format_epoch_nanos(self.epoch_nanos, Precision::Auto, None)
+    }
+
+    /// Returns the IXDTF string for this `Instant` rounded according to the options, e.g.
+    /// `2024-05-04T19:00-05:00`.
+    ///
+    /// The options are resolved with `ToStringRoundingOptions::resolve`, and the rounding mode
+    /// defaults to `trunc`.
+    ///
+    /// The time is displayed at the UTC offset in minutes if provided, and in UTC with a `Z`
+    /// otherwise. The `Instant` is rounded before it is projected to the offset, so rounding
+    /// never changes the displayed offset.
+    pub fn to_ixdtf_string_with_options(
+        &self,
+        offset_minutes: Option<i32>,
+        options: ToStringRoundingOptions,
+    ) -> TemporalResult<String> {
+        let resolved = options.resolve()?;
+        let offset_nanos = offset_minutes
+            .map(validate_offset_nanoseconds)
+            .transpose()?
+            .unwrap_or(0);
+
+        let rounded = self.round_instant(ResolvedRoundingOptions {
+            largest_unit: TemporalUnit::Auto,
+            smallest_unit: resolved.smallest_unit,
+            increment: resolved.increment,
+            rounding_mode: resolved.rounding_mode,
+        })?;
+
+        Ok(format_epoch_nanos(
+            rounded + offset_nanos,
+            resolved.precision,
+            offset_minutes,
+        ))
+    }
+
+    /// Returns this `Instant` truncated to microsecond precision.
+    ///
+    /// The sub-microsecond nanoseconds are removed by an exact integer truncation toward negative
+    /// infinity, so an `Instant` before the Unix epoch moves further into the past.
+    #[must_use]
+    pub fn truncate_to_microseconds(&self) -> Self {
+        Self {
+            epoch_nanos: self.epoch_nanos.div_euclid(1_000) * 1_000,
+        }
+    }
+
+    /// Returns this `Instant` truncated to millisecond precision.
+    ///
+    /// The sub-millisecond nanoseconds are removed by an exact integer truncation toward negative
+    /// infinity, so an `Instant` before the Unix epoch moves further into the past.
+    #[must_use]
+    pub fn truncate_to_milliseconds(&self) -> Self {
+        Self {
+            epoch_nanos: self.epoch_nanos.div_euclid(1_000_000) * 1_000_000,
+        }
+    }
 }
 
 // ==== Utility Functions ====
 
+/// Formats local epoch nanoseconds as an IXDTF date-time string with the provided precision,
+/// followed by the UTC offset in minutes, or a `Z` if there is no offset.
+fn format_epoch_nanos(
+    local_nanos: i128,
+    precision: Precision,
+    offset_minutes: Option<i32>,
+) -> String {
+    let (epoch_days, nanos_of_day) = local_nanos.div_rem_euclid(&(NS_PER_DAY as i128));
+    // NOTE: Epoch nanoseconds near the valid limits are always within an `i32` range of days.
+    let date = IsoDate::from_epoch_days(epoch_days as i32);
+
+    let (second_of_day, nanos) = nanos_of_day.div_rem_euclid(&1_000_000_000);
+    let (hour, minute, second) = (
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60,
+    );
+
+    let mut result = format!(
+        "{}-{:02}-{:02}T{hour:02}:{minute:02}",
+        pad_iso_year(date.year),
+        date.month,
+        date.day,
+    );
+    if precision != Precision::Minute {
+        result.push_str(&format!(":{second:02}"));
+        // NOTE: The remainder is within `0..1_000_000_000`, so it fits a `u32`.
+        result.push_str(&format_fractional_seconds(nanos as u32, precision));
+    }
+
+    match offset_minutes {
+        None => result.push('Z'),
+        Some(offset) => {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.unsigned_abs();
+            result.push_str(&format!("{sign}{:02}:{:02}", offset / 60, offset % 60));
+        }
+    }
+    result
+}
+
+#[inline]
+fn instant_range_error() -> TemporalError {
+    TemporalError::range().with_message("Instant nanoseconds are not within a valid epoch range.")
+}
+
+/// Returns the total nanoseconds of a `core::time::Duration` as an `i128`.
+#[inline]
+fn std_duration_nanos(duration: core::time::Duration) -> TemporalResult<i128> {
+    i128::try_from(duration.as_nanos()).map_err(|_| instant_range_error())
+}
+
 /// Utility for determining if the nanos are within a valid range.
 #[inline]
 #[must_use]
@@ -303,14 +577,29 @@ impl FromStr for Instant {
         )?;
 
         // Find the offset
-        let offset = f64::from(ixdtf_record.offset.hour) * NANOSECONDS_PER_HOUR
-            + f64::from(ixdtf_record.offset.minute) * NANOSECONDS_PER_MINUTE
-            + f64::from(ixdtf_record.offset.second) * NANOSECONDS_PER_SECOND
-            + f64::from(ixdtf_record.offset.nanosecond);
+        let offset_nanos = i128::from(utc_offset_nanoseconds(&ixdtf_record.offset));
 
-        let nanoseconds = IsoDateTime::new_unchecked(iso_date, iso_time).as_nanoseconds(offset);
+        // NOTE: The epoch nanoseconds are calculated with integers, so they are exact.
+        let local_nanos = i128::from(iso_date.to_epoch_days()) * i128::from(NS_PER_DAY)
+            + i128::from(iso_time.to_nanoseconds_since_midnight());
 
-        Self::from_epoch_milliseconds(nanoseconds.unwrap_or(i128::MAX))
+        Self::new(local_nanos - offset_nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for Instant {
+    type Error = TemporalError;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        let epoch_nanos = match value.duration_since(UNIX_EPOCH) {
+            Ok(duration) => i128::try_from(duration.as_nanos()).ok(),
+            // NOTE: A `SystemTime` before the Unix epoch errors with the duration to the epoch.
+            Err(e) => i128::try_from(e.duration().as_nanos())
+                .ok()
+                .map(|nanos| -nanos),
+        };
+        Self::new(epoch_nanos.unwrap_or(i128::MAX))
     }
 }
 
@@ -318,12 +607,19 @@ impl FromStr for Instant {
 
 #[cfg(test)]
 mod tests {
+    use core::str::FromStr;
+
     use crate::{
-        components::{duration::TimeDuration, Instant},
-        options::{DifferenceSettings, TemporalRoundingMode, TemporalUnit},
+        components::{duration::TimeDuration, Duration, Instant},
+        error::ErrorKind,
+        options::{
+            DifferenceSettings, Precision, RoundingIncrement, RoundingOptions,
+            TemporalRoundingMode, TemporalUnit, ToStringRoundingOptions,
+        },
         primitive::FiniteF64,
         NS_MAX_INSTANT, NS_MIN_INSTANT,
     };
+    use num_bigint::BigInt;
     use num_traits::ToPrimitive;
 
     #[test]
@@ -503,4 +799,597 @@ mod tests {
             (-376435.0, -23.0, -8.0, -148.0, -529.0, -500.0),
         );
     }
+
+    #[test]
+    fn truncate_instant_precision() {
+        // 1976-11-18T15:23:30.123456789Z
+        let instant = Instant::new(217_178_610_123_456_789).unwrap();
+        assert_eq!(
+            instant.truncate_to_microseconds().epoch_nanos,
+            217_178_610_123_456_000
+        );
+        assert_eq!(
+            instant.truncate_to_milliseconds().epoch_nanos,
+            217_178_610_123_000_000
+        );
+
+        // 1969-12-31T23:59:59.998765433Z
+        let pre_epoch = Instant::new(-1_234_567).unwrap();
+        assert_eq!(pre_epoch.truncate_to_microseconds().epoch_nanos, -1_235_000);
+        assert_eq!(pre_epoch.truncate_to_milliseconds().epoch_nanos, -2_000_000);
+
+        // Values already at the requested precision are unchanged.
+        let min = Instant::new(NS_MIN_INSTANT).unwrap();
+        assert_eq!(min.truncate_to_milliseconds(), min);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_from_system_time() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let instant =
+            Instant::try_from(UNIX_EPOCH + Duration::new(217_178_610, 123_456_789)).unwrap();
+        assert_eq!(instant.epoch_nanos, 217_178_610_123_456_789);
+
+        let instant = Instant::try_from(UNIX_EPOCH - Duration::new(1, 500)).unwrap();
+        assert_eq!(instant.epoch_nanos, -1_000_000_500);
+
+        let min = UNIX_EPOCH - Duration::from_secs(8_640_000_000_000);
+        assert_eq!(Instant::try_from(min).unwrap().epoch_nanos, NS_MIN_INSTANT);
+        assert!(Instant::try_from(min - Duration::from_nanos(1)).is_err());
+
+        let max = UNIX_EPOCH + Duration::from_secs(8_640_000_000_000);
+        assert_eq!(Instant::try_from(max).unwrap().epoch_nanos, NS_MAX_INSTANT);
+        assert!(Instant::try_from(max + Duration::from_nanos(1)).is_err());
+
+        assert!(Instant::try_from(SystemTime::now()).is_ok());
+    }
+
+    #[test]
+    fn instant_to_ixdtf_string() {
+        let max = Instant::new(NS_MAX_INSTANT).unwrap();
+        assert_eq!(max.to_ixdtf_string(), "+275760-09-13T00:00:00Z");
+        let max_minus_one = Instant::new(NS_MAX_INSTANT - 1).unwrap();
+        assert_eq!(
+            max_minus_one.to_ixdtf_string(),
+            "+275760-09-12T23:59:59.999999999Z"
+        );
+
+        let min = Instant::new(NS_MIN_INSTANT).unwrap();
+        assert_eq!(min.to_ixdtf_string(), "-271821-04-20T00:00:00Z");
+        let min_plus_one = Instant::new(NS_MIN_INSTANT + 1).unwrap();
+        assert_eq!(
+            min_plus_one.to_ixdtf_string(),
+            "-271821-04-20T00:00:00.000000001Z"
+        );
+
+        let instant = Instant::new(217_178_610_123_456_789).unwrap();
+        assert_eq!(instant.to_ixdtf_string(), "1976-11-18T15:23:30.123456789Z");
+        let instant = Instant::new(-1_500_000_000).unwrap();
+        assert_eq!(instant.to_ixdtf_string(), "1969-12-31T23:59:58.5Z");
+    }
+
+    #[test]
+    fn round_instant_exact_near_limits() {
+        let options = |unit: TemporalUnit, mode: TemporalRoundingMode| RoundingOptions {
+            largest_unit: None,
+            smallest_unit: Some(unit),
+            increment: None,
+            rounding_mode: Some(mode),
+        };
+
+        // 275760-09-12T23:59:29.999999999Z
+        let instant = Instant::new(NS_MAX_INSTANT - 30_000_000_001).unwrap();
+        let result = instant
+            .round(options(
+                TemporalUnit::Minute,
+                TemporalRoundingMode::HalfExpand,
+            ))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, NS_MAX_INSTANT - 60_000_000_000);
+        let result = instant
+            .round(options(TemporalUnit::Minute, TemporalRoundingMode::Ceil))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, NS_MAX_INSTANT);
+        let result = instant
+            .round(options(
+                TemporalUnit::Nanosecond,
+                TemporalRoundingMode::Ceil,
+            ))
+            .unwrap();
+        assert_eq!(result, instant);
+
+        // Pre-epoch instants are rounded as if positive.
+        let instant = Instant::new(NS_MIN_INSTANT + 90_000_000_000).unwrap();
+        let result = instant
+            .round(options(TemporalUnit::Minute, TemporalRoundingMode::Trunc))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, NS_MIN_INSTANT + 60_000_000_000);
+        let result = instant
+            .round(options(
+                TemporalUnit::Minute,
+                TemporalRoundingMode::HalfEven,
+            ))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, NS_MIN_INSTANT + 120_000_000_000);
+
+        let instant = Instant::new(-1_500_000_000).unwrap();
+        let result = instant
+            .round(options(TemporalUnit::Second, TemporalRoundingMode::Floor))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, -2_000_000_000);
+        let result = instant
+            .round(options(
+                TemporalUnit::Second,
+                TemporalRoundingMode::HalfTrunc,
+            ))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, -2_000_000_000);
+    }
+
+    #[test]
+    fn diff_rejects_inverted_units() {
+        let earlier = Instant::new(217_178_610_123_456_789).unwrap();
+        let later = Instant::new(1_572_345_998_271_986_289).unwrap();
+
+        let settings = DifferenceSettings::new()
+            .largest_unit(TemporalUnit::Second)
+            .smallest_unit(TemporalUnit::Hour);
+        assert!(earlier.until(&later, settings).is_err());
+        assert!(earlier.since(&later, settings).is_err());
+
+        let settings = DifferenceSettings::new()
+            .largest_unit(TemporalUnit::Minute)
+            .smallest_unit(TemporalUnit::Minute);
+        assert!(earlier.until(&later, settings).is_ok());
+    }
+
+    #[test]
+    fn from_rfc3339_str() {
+        let expected = 1_714_842_324_123_456_789;
+        let instant = Instant::from_rfc3339_str("2024-05-04T17:05:24.123456789Z").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+        let instant = Instant::from_rfc3339_str("2024-05-04T17:05:24.123456789+00:00").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+        let instant = Instant::from_rfc3339_str("2024-05-04T12:05:24.123456789-05:00").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+        let instant = Instant::from_rfc3339_str("2024-05-04T22:35:24.123456789+05:30").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+
+        // Fractions beyond nanosecond precision are truncated.
+        let instant = Instant::from_rfc3339_str("2024-05-04T17:05:24.1234567899Z").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+
+        // Pre-epoch values are exact.
+        let instant = Instant::from_rfc3339_str("1969-12-31T23:59:59.999999999Z").unwrap();
+        assert_eq!(instant.epoch_nanos, -1);
+
+        assert!(Instant::from_rfc3339_str("2024-05-04T17:05:24.123456789").is_err());
+        assert!(Instant::from_rfc3339_str("2024-05-04T17:05:24Z[UTC]").is_err());
+
+        // RFC 3339 allows a lowercase `t` and `z`, or a space as the separator.
+        let instant = Instant::from_rfc3339_str("2024-05-04t17:05:24.123456789z").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+        let instant = Instant::from_rfc3339_str("2024-05-04 17:05:24.123456789Z").unwrap();
+        assert_eq!(instant.epoch_nanos, expected);
+
+        // IXDTF forms that are not RFC 3339 are rejected.
+        for s in [
+            "20240504T170524Z",
+            "+002024-05-04T17:05:24Z",
+            "2024-05-04T17:05Z",
+            "2024-05-04T17:05:24,123Z",
+            "2024-05-04T17:05:24.Z",
+            "2024-05-04_17:05:24Z",
+            "2024-05-04T17:05:24+0500",
+            "2024-05-04T17:05:24+05",
+            "2024-05-04T17:05:24+05:00:00",
+        ] {
+            assert!(Instant::from_rfc3339_str(s).is_err(), "{s}");
+        }
+        assert!("2024-05-04T17:05:24.123456789Z[UTC]"
+            .parse::<Instant>()
+            .is_ok_and(|instant| instant.epoch_nanos == expected));
+    }
+
+    #[test]
+    fn epoch_and_limit_constants() {
+        assert_eq!(Instant::UNIX_EPOCH.epoch_nanoseconds(), 0.0);
+        assert_eq!(Instant::UNIX_EPOCH, Instant::new(0).unwrap());
+        assert_eq!(Instant::MIN, Instant::new(NS_MIN_INSTANT).unwrap());
+        assert_eq!(Instant::MAX, Instant::new(NS_MAX_INSTANT).unwrap());
+        assert!(Instant::MIN < Instant::UNIX_EPOCH && Instant::UNIX_EPOCH < Instant::MAX);
+
+        assert_eq!(Instant::MIN.to_ixdtf_string(), "-271821-04-20T00:00:00Z");
+        assert_eq!(Instant::MAX.to_ixdtf_string(), "+275760-09-13T00:00:00Z");
+        assert_eq!(
+            Instant::UNIX_EPOCH.to_ixdtf_string(),
+            "1970-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn from_str_fraction_digits() {
+        let seconds = 1_714_842_324_000_000_000;
+        let cases = [
+            ("1", 100_000_000),
+            ("12", 120_000_000),
+            ("123", 123_000_000),
+            ("1234", 123_400_000),
+            ("12345", 123_450_000),
+            ("123456", 123_456_000),
+            ("1234567", 123_456_700),
+            ("12345678", 123_456_780),
+            ("123456789", 123_456_789),
+            // Digits beyond nanosecond precision are truncated, not rounded.
+            ("1234567891", 123_456_789),
+            ("9999999999999", 999_999_999),
+        ];
+        for (fraction, nanos) in cases {
+            let instant = format!("2024-05-04T17:05:24.{fraction}Z")
+                .parse::<Instant>()
+                .unwrap();
+            assert_eq!(instant.epoch_nanos, seconds + nanos, "fraction {fraction}");
+        }
+
+        // The fraction is combined exactly with the offset and pre-epoch dates.
+        let instant = "1969-12-31T19:59:59,0000000015-04:00"
+            .parse::<Instant>()
+            .unwrap();
+        assert_eq!(instant.epoch_nanos, -999_999_999);
+    }
+
+    #[test]
+    fn diff_rejects_date_units() {
+        let earlier = Instant::new(0).unwrap();
+        let later = Instant::new(3 * 86_400_000_000_000 + 1).unwrap();
+
+        for unit in [
+            TemporalUnit::Year,
+            TemporalUnit::Month,
+            TemporalUnit::Week,
+            TemporalUnit::Day,
+        ] {
+            let settings = DifferenceSettings::new().largest_unit(unit);
+            let err = earlier.until(&later, settings).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range, "{unit}");
+            assert!(earlier.since(&later, settings).is_err(), "{unit}");
+
+            let settings = DifferenceSettings::new().smallest_unit(unit);
+            assert!(earlier.until(&later, settings).is_err(), "{unit}");
+        }
+
+        // Hour is the coarsest unit, so days are expressed as hours.
+        let settings = DifferenceSettings::new().largest_unit(TemporalUnit::Hour);
+        let result = earlier.until(&later, settings).unwrap();
+        assert_eq!(result.hours, 72.0);
+        assert_eq!(result.nanoseconds, 1.0);
+        let result = earlier.since(&later, settings).unwrap();
+        assert_eq!(result.hours, -72.0);
+    }
+
+    #[test]
+    fn round_increment_may_be_a_whole_day() {
+        // 2024-05-04T17:05:24.123456789Z
+        let instant = Instant::new(1_714_842_324_123_456_789).unwrap();
+        let options = |unit, increment| RoundingOptions {
+            largest_unit: None,
+            smallest_unit: Some(unit),
+            increment: Some(RoundingIncrement::try_new(increment).unwrap()),
+            rounding_mode: None,
+        };
+
+        // 2024-05-05T00:00:00Z
+        let midnight = 1_714_867_200_000_000_000;
+        let result = instant.round(options(TemporalUnit::Hour, 24)).unwrap();
+        assert_eq!(result.epoch_nanos, midnight);
+        let result = instant
+            .round(options(TemporalUnit::Second, 86_400))
+            .unwrap();
+        assert_eq!(result.epoch_nanos, midnight);
+
+        // The increment must still divide a day evenly and not exceed it.
+        assert!(instant.round(options(TemporalUnit::Hour, 25)).is_err());
+        assert!(instant.round(options(TemporalUnit::Hour, 7)).is_err());
+    }
+
+    #[test]
+    fn equality_is_exact() {
+        let instant = Instant::new(1_700_000_000_000_000_000).unwrap();
+        let next = Instant::new(1_700_000_000_000_000_001).unwrap();
+        assert!(instant.equals(&instant.clone()));
+        assert!(!instant.equals(&next));
+        assert_ne!(instant, next);
+
+        // The lossy `f64` values can not tell the instants apart.
+        assert_eq!(instant.epoch_milliseconds(), next.epoch_milliseconds());
+        assert_eq!(instant.epoch_nanoseconds(), next.epoch_nanoseconds());
+        assert_eq!(instant.nanoseconds_until(&next), 1);
+    }
+
+    #[test]
+    fn to_ixdtf_string_fractional_digits() {
+        // 2024-05-04T17:05:24.12Z
+        let instant = Instant::new(1_714_842_324_120_000_000).unwrap();
+        let to_string = |precision| {
+            instant
+                .to_ixdtf_string_with_options(
+                    None,
+                    ToStringRoundingOptions {
+                        precision,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+        };
+
+        assert_eq!(to_string(Precision::Digit(0)), "2024-05-04T17:05:24Z");
+        assert_eq!(to_string(Precision::Digit(1)), "2024-05-04T17:05:24.1Z");
+        assert_eq!(to_string(Precision::Digit(3)), "2024-05-04T17:05:24.120Z");
+        assert_eq!(
+            to_string(Precision::Digit(6)),
+            "2024-05-04T17:05:24.120000Z"
+        );
+        assert_eq!(
+            to_string(Precision::Digit(9)),
+            "2024-05-04T17:05:24.120000000Z"
+        );
+        assert_eq!(to_string(Precision::Auto), "2024-05-04T17:05:24.12Z");
+        assert_eq!(to_string(Precision::Minute), "2024-05-04T17:05Z");
+
+        // Whole seconds omit the decimal point unless digits are requested.
+        let instant = Instant::new(1_714_842_324_000_000_000).unwrap();
+        assert_eq!(instant.to_ixdtf_string(), "2024-05-04T17:05:24Z");
+        let result = instant
+            .to_ixdtf_string_with_options(
+                None,
+                ToStringRoundingOptions {
+                    precision: Precision::Digit(3),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(result, "2024-05-04T17:05:24.000Z");
+    }
+
+    #[test]
+    fn to_ixdtf_string_rounds_before_offset() {
+        // 2024-05-04T23:59:40.25Z
+        let instant = Instant::new(1_714_867_180_250_000_000).unwrap();
+        let options = |smallest_unit, rounding_mode| ToStringRoundingOptions {
+            precision: Precision::Auto,
+            smallest_unit,
+            rounding_mode,
+        };
+        let to_string = |offset, smallest_unit, rounding_mode| {
+            instant
+                .to_ixdtf_string_with_options(offset, options(smallest_unit, rounding_mode))
+                .unwrap()
+        };
+
+        // Rounding up to the minute rolls over the hour and the day, without moving the offset.
+        let ceil = Some(TemporalRoundingMode::Ceil);
+        let minute = Some(TemporalUnit::Minute);
+        assert_eq!(to_string(None, minute, ceil), "2024-05-05T00:00Z");
+        assert_eq!(to_string(Some(0), minute, ceil), "2024-05-05T00:00+00:00");
+        assert_eq!(
+            to_string(Some(-300), minute, ceil),
+            "2024-05-04T19:00-05:00"
+        );
+        assert_eq!(to_string(Some(330), minute, ceil), "2024-05-05T05:30+05:30");
+        assert_eq!(
+            to_string(Some(-300), minute, None),
+            "2024-05-04T18:59-05:00"
+        );
+
+        let second = Some(TemporalUnit::Second);
+        assert_eq!(
+            to_string(Some(-300), second, ceil),
+            "2024-05-04T18:59:41-05:00"
+        );
+        assert_eq!(
+            to_string(None, Some(TemporalUnit::Millisecond), None),
+            "2024-05-04T23:59:40.250Z"
+        );
+        assert_eq!(
+            to_string(None, Some(TemporalUnit::Nanosecond), None),
+            "2024-05-04T23:59:40.250000000Z"
+        );
+        assert_eq!(to_string(None, None, None), instant.to_ixdtf_string());
+        assert_eq!(to_string(None, None, None), "2024-05-04T23:59:40.25Z");
+
+        assert!(instant
+            .to_ixdtf_string_with_options(None, options(Some(TemporalUnit::Hour), None))
+            .is_err());
+        assert!(instant
+            .to_ixdtf_string_with_options(Some(1440), options(None, None))
+            .is_err());
+    }
+
+    #[test]
+    fn arithmetic_overflow_errors() {
+        let hour = Duration::from_str("PT1H").unwrap();
+        let nanos = Duration::from_str("PT0.000000001S").unwrap();
+
+        let max = Instant::MAX;
+        let err = max.add(nanos).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(
+            err.message(),
+            "Instant arithmetic exceeded the maximum instant by 1ns."
+        );
+        let err = max.subtract(hour.negated()).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Instant arithmetic exceeded the maximum instant by 3600000000000ns."
+        );
+        let err = max.add_std(core::time::Duration::from_secs(1)).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Instant arithmetic exceeded the maximum instant by 1000000000ns."
+        );
+
+        let min = Instant::MIN;
+        let err = min.subtract(hour).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(
+            err.message(),
+            "Instant arithmetic exceeded the minimum instant by 3600000000000ns."
+        );
+        let err = min.add(nanos.negated()).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Instant arithmetic exceeded the minimum instant by 1ns."
+        );
+
+        // Large time durations are added exactly, without any floating point rounding.
+        let instant = Instant::new(NS_MAX_INSTANT - 1).unwrap();
+        let result = instant
+            .add(Duration::from_str("PT1H0.000000001S").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            result.message(),
+            "Instant arithmetic exceeded the maximum instant by 3600000000000ns."
+        );
+        let result = Instant::MIN
+            .add(Duration::from_str("PT2400000000H0.000000001S").unwrap())
+            .unwrap();
+        assert_eq!(
+            result.epoch_nanos,
+            NS_MIN_INSTANT + 2_400_000_000 * 3_600_000_000_000 + 1
+        );
+    }
+
+    #[test]
+    fn add_and_subtract_std_duration() {
+        let instant = Instant::new(1_000).unwrap();
+        let duration = core::time::Duration::new(1, 999_999_999);
+        assert_eq!(
+            instant.add_std(duration).unwrap().epoch_nanos,
+            2_000_000_999
+        );
+        assert_eq!(
+            instant.subtract_std(duration).unwrap().epoch_nanos,
+            -1_999_998_999
+        );
+
+        let max = Instant::new(NS_MAX_INSTANT).unwrap();
+        assert!(max.add_std(core::time::Duration::ZERO).is_ok());
+        assert!(max.add_std(core::time::Duration::from_nanos(1)).is_err());
+        let min = Instant::new(NS_MIN_INSTANT).unwrap();
+        assert!(min
+            .subtract_std(core::time::Duration::from_nanos(1))
+            .is_err());
+        assert!(min.add_std(core::time::Duration::MAX).is_err());
+    }
+
+    #[test]
+    fn round_half_even() {
+        let options = RoundingOptions {
+            largest_unit: None,
+            smallest_unit: Some(TemporalUnit::Microsecond),
+            increment: None,
+            rounding_mode: Some(TemporalRoundingMode::HalfEven),
+        };
+        let round = |nanos| {
+            Instant::new(nanos)
+                .unwrap()
+                .round(options)
+                .unwrap()
+                .epoch_nanos
+        };
+        assert_eq!(round(2_500), 2_000);
+        assert_eq!(round(3_500), 4_000);
+        assert_eq!(round(-2_500), -2_000);
+        assert_eq!(round(-3_500), -4_000);
+    }
+
+    #[test]
+    fn to_plain_time_iso_with_offsets() {
+        // 2024-05-04T00:30:00.000000001Z
+        let instant = Instant::new(1_714_782_600_000_000_001).unwrap();
+        let time = instant.to_plain_time_iso(0).unwrap();
+        assert_eq!((time.hour(), time.minute(), time.nanosecond()), (0, 30, 1));
+
+        // A negative offset moves the wall-clock time into the previous day.
+        let time = instant.to_plain_time_iso(-300).unwrap();
+        assert_eq!((time.hour(), time.minute(), time.nanosecond()), (19, 30, 1));
+        let time = instant.to_plain_time_iso(-1439).unwrap();
+        assert_eq!((time.hour(), time.minute()), (0, 31));
+
+        let time = Instant::new(-1).unwrap().to_plain_time_iso(330).unwrap();
+        assert_eq!(
+            (time.hour(), time.minute(), time.second(), time.nanosecond()),
+            (5, 29, 59, 999)
+        );
+        let time = Instant::new(NS_MIN_INSTANT)
+            .unwrap()
+            .to_plain_time_iso(-1)
+            .unwrap();
+        assert_eq!((time.hour(), time.minute()), (23, 59));
+
+        assert!(instant.to_plain_time_iso(1440).is_err());
+        assert!(instant.to_plain_time_iso(-1440).is_err());
+    }
+
+    #[test]
+    fn round_to_bigint_matches_round() {
+        let options = RoundingOptions {
+            largest_unit: None,
+            smallest_unit: Some(TemporalUnit::Hour),
+            increment: None,
+            rounding_mode: Some(TemporalRoundingMode::Ceil),
+        };
+        let max = Instant::new(NS_MAX_INSTANT - 1).unwrap();
+        assert_eq!(
+            max.round_to_bigint(options).unwrap(),
+            BigInt::from(NS_MAX_INSTANT)
+        );
+        assert_eq!(max.round(options).unwrap().epoch_nanos, NS_MAX_INSTANT);
+
+        let instant = Instant::new(-1_800_000_000_001).unwrap();
+        assert_eq!(instant.round_to_bigint(options).unwrap(), BigInt::from(0));
+
+        let options = RoundingOptions {
+            smallest_unit: Some(TemporalUnit::Day),
+            ..options
+        };
+        assert!(instant.round_to_bigint(options).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn exact_epoch_getters() {
+        let instant = Instant::new(-1).unwrap();
+        assert_eq!(instant.epoch_seconds_i64(), -1);
+        assert_eq!(instant.epoch_milliseconds_i64(), -1);
+        assert_eq!(instant.epoch_microseconds_i128(), -1);
+        assert_eq!(instant.epoch_milliseconds(), -1.0);
+
+        let instant = Instant::new(1_714_842_324_123_456_789).unwrap();
+        assert_eq!(instant.epoch_seconds_i64(), 1_714_842_324);
+        assert_eq!(instant.epoch_milliseconds_i64(), 1_714_842_324_123);
+        assert_eq!(instant.epoch_microseconds_i128(), 1_714_842_324_123_456);
+
+        let max = Instant::new(NS_MAX_INSTANT).unwrap();
+        assert_eq!(max.epoch_milliseconds_i64(), 8_640_000_000_000_000);
+        let min = Instant::new(NS_MIN_INSTANT).unwrap();
+        assert_eq!(min.epoch_milliseconds_i64(), -8_640_000_000_000_000);
+        assert_eq!(min.epoch_seconds_i64(), -8_640_000_000_000);
+    }
+
+    #[test]
+    fn nanoseconds_until_is_exact() {
+        let min = Instant::new(NS_MIN_INSTANT).unwrap();
+        let max = Instant::new(NS_MAX_INSTANT).unwrap();
+        assert_eq!(min.nanoseconds_until(&max), 17_280_000_000_000_000_000_000);
+        assert_eq!(max.nanoseconds_until(&min), -17_280_000_000_000_000_000_000);
+        assert_eq!(max.nanoseconds_until(&max), 0);
+
+        // A single nanosecond is preserved next to the largest magnitudes.
+        let almost_max = Instant::new(NS_MAX_INSTANT - 1).unwrap();
+        assert_eq!(almost_max.nanoseconds_until(&max), 1);
+        assert_eq!(min.nanoseconds_until(&almost_max), 2 * NS_MAX_INSTANT - 1);
+    }
 }
@@ -10,6 +10,8 @@ pub mod calendar;
 pub mod duration;
 pub mod tz;
 
+#[cfg(feature = "chrono")]
+mod chrono;
 mod date;
 mod datetime;
 mod instant;
@@ -19,7 +21,7 @@ mod year_month;
 mod zoneddatetime;
 
 #[doc(inline)]
-pub use date::{PartialDate, PlainDate};
+pub use date::{PartialDate, PlainDate, WeekInfo, Weekday};
 #[doc(inline)]
 pub use datetime::{PartialDateTime, PlainDateTime};
 #[doc(inline)]
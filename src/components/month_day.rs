@@ -1,13 +1,14 @@
 //! This module implements `MonthDay` and any directly related algorithms.
 
-use std::str::FromStr;
+use core::{fmt, str::FromStr};
 
 use tinystr::TinyAsciiStr;
 
 use crate::{
-    components::calendar::Calendar,
+    components::{calendar::Calendar, PartialDate, PlainDate},
     iso::{IsoDate, IsoDateSlots},
     options::ArithmeticOverflow,
+    utils::pad_iso_year,
     TemporalError, TemporalResult, TemporalUnwrap,
 };
 
@@ -42,6 +43,38 @@ impl PlainMonthDay {
         Ok(Self::new_unchecked(iso, calendar))
     }
 
+    /// Creates a new `MonthDay` with values from a `PartialDate`.
+    ///
+    /// Fields that are not set on the partial are taken from this `MonthDay`, and a provided
+    /// `month` or `monthCode` replaces both of the current month fields. The leap year 1972 is
+    /// kept as the reference ISO year, so a provided `year` only determines how the day is
+    /// constrained or rejected.
+    pub fn with(
+        &self,
+        partial: PartialDate,
+        overflow: Option<ArithmeticOverflow>,
+    ) -> TemporalResult<Self> {
+        if partial.is_empty() {
+            return Err(TemporalError::r#type().with_message("A PartialDate must have a field."));
+        }
+        if !self.calendar.is_iso() {
+            return Err(TemporalError::range().with_message("Not yet implemented."));
+        }
+        let month_code = if partial.month.is_none() && partial.month_code.is_none() {
+            Some(self.month_code()?)
+        } else {
+            partial.month_code
+        };
+        let fields = PartialDate {
+            year: partial.year.or(Some(self.iso.year)),
+            month_code,
+            day: partial.day.or(Some(self.iso.day.into())),
+            ..partial
+        };
+        self.calendar
+            .month_day_from_partial(&fields, overflow.unwrap_or(ArithmeticOverflow::Constrain))
+    }
+
     /// Returns the iso day value of `MonthDay`.
     #[inline]
     #[must_use]
@@ -75,6 +108,22 @@ impl PlainMonthDay {
     pub fn month_code(&self) -> TemporalResult<TinyAsciiStr<4>> {
         self.calendar.month_code(&CalendarDateLike::MonthDay(self))
     }
+
+    /// Creates a `PlainDate` from this `MonthDay` in the provided ISO year.
+    ///
+    /// The day is constrained to the month, so `--02-29` in a common year is February 28th.
+    pub fn to_plain_date(&self, year: i32) -> TemporalResult<PlainDate> {
+        if !self.calendar.is_iso() {
+            return Err(TemporalError::range().with_message("Not yet implemented."));
+        }
+        PlainDate::new_with_overflow(
+            year,
+            self.iso.month.into(),
+            self.iso.day.into(),
+            self.calendar.clone(),
+            ArithmeticOverflow::Constrain,
+        )
+    }
 }
 
 impl GetTemporalCalendar for PlainMonthDay {
@@ -111,3 +160,109 @@ impl FromStr for PlainMonthDay {
         )
     }
 }
+
+impl fmt::Display for PlainMonthDay {
+    /// Formats the `MonthDay` as `MM-DD`, or with the reference ISO year and a calendar
+    /// annotation when the calendar is not `iso8601`, e.g. `1972-12-25[u-ca=gregory]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.calendar.is_iso() {
+            return write!(f, "{:02}-{:02}", self.iso.month, self.iso.day);
+        }
+        write!(
+            f,
+            "{}-{:02}-{:02}[u-ca={}]",
+            pad_iso_year(self.iso.year),
+            self.iso.month,
+            self.iso.day,
+            self.calendar.identifier()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        components::{PartialDate, PlainDate},
+        iso::IsoDateSlots,
+        options::ArithmeticOverflow,
+    };
+
+    use super::PlainMonthDay;
+
+    #[test]
+    fn leap_day_projection_round_trip() {
+        let leap_day = PlainDate::from_str("2024-02-29").unwrap();
+        let md = leap_day.to_plain_month_day().unwrap();
+        assert_eq!((md.iso_month(), md.iso_day()), (2, 29));
+        assert_eq!(md.to_string(), "02-29");
+        assert_eq!(md.to_plain_date(2024).unwrap(), leap_day);
+
+        // The leap day is constrained when materialized in a common year.
+        let date = md.to_plain_date(2023).unwrap();
+        assert_eq!(
+            (date.iso_year(), date.iso_month(), date.iso_day()),
+            (2023, 2, 28)
+        );
+
+        let ym = leap_day.to_plain_year_month().unwrap();
+        assert_eq!((ym.iso_year(), ym.iso_month()), (2024, 2));
+        assert_eq!(ym.calendar_id(), "iso8601");
+        assert_eq!(ym.to_string(), "2024-02");
+    }
+
+    #[test]
+    fn month_day_from_str_and_display() {
+        let md = PlainMonthDay::from_str("--12-25").unwrap();
+        assert_eq!((md.iso_month(), md.iso_day()), (12, 25));
+        assert_eq!(md.to_string(), "12-25");
+
+        // A full date string is accepted and its year is dropped.
+        let md = PlainMonthDay::from_str("2023-02-28T10:00[u-ca=iso8601]").unwrap();
+        assert_eq!(md.to_string(), "02-28");
+        assert_eq!(
+            PlainMonthDay::from_str("--02-29").unwrap().to_string(),
+            "02-29"
+        );
+
+        let md = PlainMonthDay::from_str("2024-12-25[u-ca=gregory]").unwrap();
+        assert_eq!(md.calendar_id(), "gregory");
+        assert_eq!(md.to_string(), "1972-12-25[u-ca=gregory]");
+
+        assert!(PlainMonthDay::from_str("--12-25[u-ca=gregory]").is_err());
+        assert!(PlainMonthDay::from_str("--12-25[u-ca=not-a-calendar]").is_err());
+        assert!(PlainMonthDay::from_str("--13-01").is_err());
+    }
+
+    #[test]
+    fn with_month_and_day() {
+        let md = PlainMonthDay::from_str("--01-31").unwrap();
+        let partial = PartialDate {
+            month: Some(2),
+            ..Default::default()
+        };
+        let constrained = md.with(partial, None).unwrap();
+        assert_eq!(constrained.to_string(), "02-29");
+        assert_eq!(constrained.iso_date().year, 1972);
+        assert!(md.with(partial, Some(ArithmeticOverflow::Reject)).is_err());
+
+        let leap_day = PlainMonthDay::from_str("--02-29").unwrap();
+        let partial = PartialDate {
+            day: Some(28),
+            ..Default::default()
+        };
+        assert_eq!(leap_day.with(partial, None).unwrap().to_string(), "02-28");
+
+        // A provided year regulates the day but is not kept as the reference year.
+        let partial = PartialDate {
+            year: Some(2023),
+            ..Default::default()
+        };
+        let common = leap_day.with(partial, None).unwrap();
+        assert_eq!(common.to_string(), "02-28");
+        assert_eq!(common.iso_date().year, 1972);
+
+        assert!(leap_day.with(PartialDate::default(), None).is_err());
+    }
+}
@@ -9,12 +9,16 @@ use crate::{
     },
     parsers::parse_time,
     primitive::FiniteF64,
-    Sign, TemporalError, TemporalResult,
+    Sign, TemporalError, TemporalResult, NS_PER_DAY,
 };
 
-use super::{duration::normalized::NormalizedTimeDuration, PlainDateTime};
+use super::{duration::normalized::NormalizedTimeDuration, PlainDate, PlainDateTime};
 
-use std::str::FromStr;
+use core::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+use num_traits::FromPrimitive;
 
 /// A `PartialTime` represents partially filled `Time` fields.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -41,7 +45,7 @@ impl PartialTime {
 
 /// The native Rust implementation of `Temporal.PlainTime`.
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PlainTime {
     pub(crate) iso: IsoTime,
 }
@@ -63,60 +67,63 @@ impl PlainTime {
     }
 
     /// Specification equivalent to `AddTime`
-    pub(crate) fn add_normalized_time_duration(&self, norm: NormalizedTimeDuration) -> (i32, Self) {
+    pub(crate) fn add_normalized_time_duration(
+        &self,
+        norm: NormalizedTimeDuration,
+    ) -> TemporalResult<(i32, Self)> {
         // 1. Set second to second + NormalizedTimeDurationSeconds(norm).
-        let second = i64::from(self.second()) + norm.seconds();
+        let second = i128::from(self.second()) + i128::from(norm.seconds());
         // 2. Set nanosecond to nanosecond + NormalizedTimeDurationSubseconds(norm).
-        let nanosecond = i32::from(self.nanosecond()) + norm.subseconds();
+        let nanosecond = i128::from(self.nanosecond()) + i128::from(norm.subseconds());
         // 3. Return BalanceTime(hour, minute, second, millisecond, microsecond, nanosecond).
         let (day, balance_result) = IsoTime::balance(
-            f64::from(self.hour()),
-            f64::from(self.minute()),
-            second as f64,
-            f64::from(self.millisecond()),
-            f64::from(self.microsecond()),
-            f64::from(nanosecond),
-        );
+            self.hour().into(),
+            self.minute().into(),
+            second,
+            self.millisecond().into(),
+            self.microsecond().into(),
+            nanosecond,
+        )?;
 
-        (day, Self::new_unchecked(balance_result))
+        Ok((day, Self::new_unchecked(balance_result)))
     }
 
-    /// Adds a `TimeDuration` to the current `Time`.
+    /// Adds a `TimeDuration` to the current `Time`, returning the balanced days and the resulting `Time`.
     ///
     /// Spec Equivalent: `AddDurationToOrSubtractDurationFromPlainTime`.
-    pub(crate) fn add_to_time(&self, duration: &TimeDuration) -> TemporalResult<Self> {
-        let (_, result) = IsoTime::balance(
-            FiniteF64::from(self.hour()).checked_add(&duration.hours)?.0,
-            FiniteF64::from(self.minute())
-                .checked_add(&duration.minutes)?
-                .0,
-            FiniteF64::from(self.second())
-                .checked_add(&duration.seconds)?
-                .0,
-            FiniteF64::from(self.millisecond())
-                .checked_add(&duration.milliseconds)?
-                .0,
-            FiniteF64::from(self.microsecond())
-                .checked_add(&duration.microseconds)?
-                .0,
-            FiniteF64::from(self.nanosecond())
-                .checked_add(&duration.nanoseconds)?
-                .0,
-        );
+    pub(crate) fn add_to_time(&self, duration: &TimeDuration) -> TemporalResult<(i32, Self)> {
+        // NOTE: Duration fields are integral, so the conversion is exact unless it overflows.
+        let add = |field: FiniteF64, value: i128| {
+            i128::from_f64(field.as_inner())
+                .and_then(|field| field.checked_add(value))
+                .ok_or_else(|| {
+                    TemporalError::range()
+                        .with_message("Duration values exceed the supported time range.")
+                })
+        };
+        let (days, result) = IsoTime::balance(
+            add(duration.hours, self.hour().into())?,
+            add(duration.minutes, self.minute().into())?,
+            add(duration.seconds, self.second().into())?,
+            add(duration.milliseconds, self.millisecond().into())?,
+            add(duration.microseconds, self.microsecond().into())?,
+            add(duration.nanoseconds, self.nanosecond().into())?,
+        )?;
 
         // NOTE (nekevss): IsoTime::balance should never return an invalid `IsoTime`
 
-        Ok(Self::new_unchecked(result))
+        Ok((days, Self::new_unchecked(result)))
     }
 
     // TODO: Migrate to
-    /// Performs a desired difference op between two `Time`'s, returning the resulting `Duration`.
+    /// Performs a desired difference op between two `Time`'s, returning the resulting `Duration`
+    /// along with the resolved rounding options.
     pub(crate) fn diff_time(
         &self,
         op: DifferenceOperation,
         other: &PlainTime,
         settings: DifferenceSettings,
-    ) -> TemporalResult<Duration> {
+    ) -> TemporalResult<(Duration, ResolvedRoundingOptions)> {
         // 1. If operation is SINCE, let sign be -1. Otherwise, let sign be 1.
         // 2. Set other to ? ToTemporalTime(other).
         // 3. Let resolvedOptions be ? SnapshotOwnProperties(? GetOptionsObject(options), null).
@@ -132,8 +139,19 @@ impl PlainTime {
         // temporalTime.[[ISOSecond]], temporalTime.[[ISOMillisecond]], temporalTime.[[ISOMicrosecond]],
         // temporalTime.[[ISONanosecond]], other.[[ISOHour]], other.[[ISOMinute]], other.[[ISOSecond]],
         // other.[[ISOMillisecond]], other.[[ISOMicrosecond]], other.[[ISONanosecond]]).
-        let mut normalized_time = self.iso.diff(&other.iso).to_normalized();
+        let normalized_time = self.iso.diff(&other.iso).to_normalized();
 
+        let result = Self::round_time_difference(normalized_time, sign, resolved)?;
+        Ok((result, resolved))
+    }
+
+    /// Rounds and balances the normalized difference of two times into a `Duration` with the
+    /// sign of the difference operation.
+    fn round_time_difference(
+        mut normalized_time: NormalizedTimeDuration,
+        sign: Sign,
+        resolved: ResolvedRoundingOptions,
+    ) -> TemporalResult<Duration> {
         // 6. If settings.[[SmallestUnit]] is not "nanosecond" or settings.[[RoundingIncrement]] ≠ 1, then
         if resolved.smallest_unit != TemporalUnit::Nanosecond
             || resolved.increment != RoundingIncrement::ONE
@@ -148,16 +166,54 @@ impl PlainTime {
         let result = TimeDuration::from_normalized(normalized_time, resolved.largest_unit)?.1;
 
         // 8. Return ! CreateTemporalDuration(0, 0, 0, 0, sign × result.[[Hours]], sign × result.[[Minutes]], sign × result.[[Seconds]], sign × result.[[Milliseconds]], sign × result.[[Microseconds]], sign × result.[[Nanoseconds]]).
-        match sign {
-            Sign::Positive | Sign::Zero => Ok(Duration::from(result)),
-            Sign::Negative => Ok(Duration::from(result.negated())),
-        }
+        let result = match sign {
+            Sign::Positive | Sign::Zero => Duration::from(result),
+            Sign::Negative => Duration::from(result.negated()),
+        };
+        Ok(result)
     }
 }
 
 // ==== Public API ====
 
 impl PlainTime {
+    /// The latest `PlainTime`, `23:59:59.999999999`.
+    pub const MAX: Self = Self {
+        iso: IsoTime {
+            hour: 23,
+            minute: 59,
+            second: 59,
+            millisecond: 999,
+            microsecond: 999,
+            nanosecond: 999,
+        },
+    };
+
+    /// Returns a `PlainTime` at midnight, `00:00:00`.
+    #[inline]
+    #[must_use]
+    pub const fn midnight() -> Self {
+        Self {
+            iso: IsoTime {
+                hour: 0,
+                minute: 0,
+                second: 0,
+                millisecond: 0,
+                microsecond: 0,
+                nanosecond: 0,
+            },
+        }
+    }
+
+    /// Returns a `PlainTime` at noon, `12:00:00`.
+    #[inline]
+    #[must_use]
+    pub const fn noon() -> Self {
+        Self {
+            iso: IsoTime::noon(),
+        }
+    }
+
     /// Creates a new `PlainTime`, constraining any field into a valid range.
     pub fn new(
         hour: i32,
@@ -199,6 +255,9 @@ impl PlainTime {
     }
 
     /// Creates a new `PlainTime` with the provided [`ArithmeticOverflow`] option.
+    ///
+    /// Negative fields are clamped to `0` when constraining and rejected otherwise, see
+    /// [`IsoTime::new`].
     #[inline]
     pub fn new_with_overflow(
         hour: i32,
@@ -221,6 +280,25 @@ impl PlainTime {
         Ok(Self::new_unchecked(time))
     }
 
+    /// Creates a new `PlainTime` from the nanoseconds since midnight.
+    ///
+    /// Any value that is equal to or greater than the nanoseconds in a day is rejected.
+    pub fn from_nanoseconds_since_midnight(nanoseconds: u64) -> TemporalResult<Self> {
+        if nanoseconds >= NS_PER_DAY {
+            return Err(TemporalError::range()
+                .with_message("nanoseconds since midnight must be less than a day."));
+        }
+        Ok(Self::new_unchecked(IsoTime::from_nanoseconds_unchecked(
+            nanoseconds,
+        )))
+    }
+
+    /// Creates a new `PlainTime` using the fields of the provided `PartialTime`, falling back to
+    /// the current `PlainTime`'s fields for any field that is not set.
+    ///
+    /// With `ArithmeticOverflow::Constrain`, the default, every field is clamped into its valid
+    /// range, e.g. an `hour` of 25 becomes 23. With `ArithmeticOverflow::Reject`, an out of range
+    /// field is an error naming the field.
     pub fn with(
         &self,
         partial: PartialTime,
@@ -278,6 +356,26 @@ impl PlainTime {
         self.iso.nanosecond
     }
 
+    /// Returns the nanoseconds since midnight for this `PlainTime`, i.e.
+    /// `((((hour * 60 + minute) * 60 + second) * 1000 + ms) * 1000 + us) * 1000 + ns`.
+    ///
+    /// The value is a monotonic key with the same ordering as `PlainTime`'s `Ord`, which makes
+    /// it suitable for sorting and bucketing.
+    #[inline]
+    #[must_use]
+    pub fn to_nanoseconds_since_midnight(&self) -> u64 {
+        self.iso.to_nanoseconds_since_midnight()
+    }
+
+    /// Returns the total nanoseconds of the day for this `PlainTime`.
+    ///
+    /// This is the same value as `to_nanoseconds_since_midnight`, for use as a sort key.
+    #[inline]
+    #[must_use]
+    pub fn nanoseconds_of_day(&self) -> u64 {
+        self.to_nanoseconds_since_midnight()
+    }
+
     /// Add a `Duration` to the current `Time`.
     pub fn add(&self, duration: &Duration) -> TemporalResult<Self> {
         if !duration.is_time_duration() {
@@ -290,22 +388,47 @@ impl PlainTime {
     /// Adds a `TimeDuration` to the current `Time`.
     #[inline]
     pub fn add_time_duration(&self, duration: &TimeDuration) -> TemporalResult<Self> {
-        self.add_to_time(duration)
+        self.add_to_time(duration).map(|(_, result)| result)
     }
 
-    /// Subtract a `Duration` to the current `Time`.
+    /// Add a `Duration` to the current `Time`, returning the number of days that the result
+    /// overflowed into along with the resulting `Time`.
+    ///
+    /// For example, adding `PT25H` to `10:00` returns `(1, 11:00)`.
+    pub fn add_with_days(&self, duration: &Duration) -> TemporalResult<(i32, Self)> {
+        if !duration.is_time_duration() {
+            return Err(TemporalError::range()
+                .with_message("DateDuration values cannot be added to `Time`."));
+        }
+        self.add_to_time(duration.time())
+    }
+
+    /// Subtract a `Duration` from the current `Time`.
     pub fn subtract(&self, duration: &Duration) -> TemporalResult<Self> {
         if !duration.is_time_duration() {
             return Err(TemporalError::range()
-                .with_message("DateDuration values cannot be added to `Time` component."));
+                .with_message("DateDuration values cannot be subtracted from `Time`."));
         }
         self.subtract_time_duration(duration.time())
     }
 
-    /// Adds a `TimeDuration` to the current `Time`.
+    /// Subtracts a `TimeDuration` from the current `Time`.
     #[inline]
     pub fn subtract_time_duration(&self, duration: &TimeDuration) -> TemporalResult<Self> {
         self.add_to_time(&duration.negated())
+            .map(|(_, result)| result)
+    }
+
+    /// Subtract a `Duration` from the current `Time`, returning the number of days that the
+    /// result overflowed into along with the resulting `Time`.
+    ///
+    /// For example, subtracting `PT5H` from `02:00` returns `(-1, 21:00)`.
+    pub fn subtract_with_days(&self, duration: &Duration) -> TemporalResult<(i32, Self)> {
+        if !duration.is_time_duration() {
+            return Err(TemporalError::range()
+                .with_message("DateDuration values cannot be subtracted from `Time`."));
+        }
+        self.add_to_time(&duration.time().negated())
     }
 
     #[inline]
@@ -313,6 +436,18 @@ impl PlainTime {
     ///
     /// NOTE: `until` assumes the provided other time will occur in the future relative to the current.
     pub fn until(&self, other: &Self, settings: DifferenceSettings) -> TemporalResult<Duration> {
+        self.diff_time(DifferenceOperation::Until, other, settings)
+            .map(|(result, _)| result)
+    }
+
+    #[inline]
+    /// Returns the `Duration` until the provided `Time` from the current `Time`, along with the
+    /// rounding options that were resolved from the provided settings.
+    pub fn until_resolved(
+        &self,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<(Duration, ResolvedRoundingOptions)> {
         self.diff_time(DifferenceOperation::Until, other, settings)
     }
 
@@ -322,17 +457,59 @@ impl PlainTime {
     /// NOTE: `since` assumes the provided other time is in the past relative to the current.
     pub fn since(&self, other: &Self, settings: DifferenceSettings) -> TemporalResult<Duration> {
         self.diff_time(DifferenceOperation::Since, other, settings)
+            .map(|(result, _)| result)
+    }
+
+    /// Returns both the `Duration` since and the `Duration` until the provided `Time`, as
+    /// `(since, until)`.
+    ///
+    /// The difference is only computed once, and is then rounded for each operation. With a
+    /// directed rounding mode, e.g. `Floor`, `since` is rounded with the negated mode, so it is
+    /// not always the negation of `until`.
+    pub fn diff_both(
+        &self,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<(Duration, Duration)> {
+        let normalized_time = self.iso.diff(&other.iso).to_normalized();
+
+        let round = |op| {
+            let (sign, resolved) = ResolvedRoundingOptions::from_diff_settings(
+                settings,
+                op,
+                TemporalUnit::Hour,
+                TemporalUnit::Nanosecond,
+            )?;
+            Self::round_time_difference(normalized_time, sign, resolved)
+        };
+        let since = round(DifferenceOperation::Since)?;
+        let until = round(DifferenceOperation::Until)?;
+        Ok((since, until))
+    }
+
+    /// Combines this `PlainTime` with the provided `PlainDate` into a `PlainDateTime`.
+    ///
+    /// The resulting `PlainDateTime` uses the calendar of `date`.
+    pub fn to_plain_date_time(&self, date: &PlainDate) -> TemporalResult<PlainDateTime> {
+        PlainDateTime::from_date_and_time(date.clone(), *self)
     }
 
-    // TODO (nekevss): optimize and test rounding_increment type (f64 vs. u64).
     /// Rounds the current `Time` according to provided options.
+    ///
+    /// A `PlainTime` is always balanced, so no largest unit is needed: rounding up carries into
+    /// the larger fields, e.g. `00:59:40` rounded to the minute is `01:00:00`, and rounding past
+    /// the end of the day wraps around to midnight.
+    ///
+    /// Hosts with a JavaScript `Number` increment should convert it with
+    /// `RoundingIncrement::try_from(f64)` first.
     pub fn round(
         &self,
         smallest_unit: TemporalUnit,
-        rounding_increment: Option<f64>,
+        rounding_increment: Option<u32>,
         rounding_mode: Option<TemporalRoundingMode>,
     ) -> TemporalResult<Self> {
-        let increment = RoundingIncrement::try_from(rounding_increment.unwrap_or(1.0))?;
+        let increment =
+            rounding_increment.map_or(Ok(RoundingIncrement::ONE), RoundingIncrement::try_new)?;
         let rounding_mode = rounding_mode.unwrap_or(TemporalRoundingMode::HalfExpand);
 
         let max = smallest_unit
@@ -341,6 +518,9 @@ impl PlainTime {
                 TemporalError::range().with_message("smallestUnit must be a time value.")
             })?;
 
+        // NOTE: The maximum is exclusive, as rounding to an increment of the unit's wrap point,
+        // e.g. 24 hours, would always round to midnight. `Instant::round` instead allows a whole
+        // day, since the result is a meaningful point in time.
         // Safety (nekevss): to_rounding_increment returns a value in the range of a u32.
         increment.validate(u64::from(max), false)?;
 
@@ -355,6 +535,82 @@ impl PlainTime {
 
         Ok(Self::new_unchecked(result))
     }
+
+    /// Rounds each of the provided times with the same resolved options, e.g. from
+    /// `ResolvedRoundingOptions::from_options`.
+    ///
+    /// Each result is the same as calling `round` on that time with the equivalent options.
+    pub fn round_many(
+        times: &[PlainTime],
+        options: ResolvedRoundingOptions,
+    ) -> TemporalResult<Vec<PlainTime>> {
+        let max = options
+            .smallest_unit
+            .to_maximum_rounding_increment()
+            .ok_or_else(|| {
+                TemporalError::range().with_message("smallestUnit must be a time value.")
+            })?;
+        options.increment.validate(u64::from(max), false)?;
+
+        times
+            .iter()
+            .map(|time| {
+                let (_, result) = time.iso.round(options)?;
+                Ok(Self::new_unchecked(result))
+            })
+            .collect()
+    }
+}
+
+// ==== Operators ====
+//
+// NOTE: `PlainTime` arithmetic is fallible, so rather than panicking, the operators return a
+// `TemporalResult` like the methods they delegate to, e.g. `(time + duration)?`.
+
+impl Add<Duration> for PlainTime {
+    type Output = TemporalResult<Self>;
+
+    /// Equivalent to `PlainTime::add`.
+    fn add(self, rhs: Duration) -> Self::Output {
+        PlainTime::add(&self, &rhs)
+    }
+}
+
+impl Sub<Duration> for PlainTime {
+    type Output = TemporalResult<Self>;
+
+    /// Equivalent to `PlainTime::subtract`.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.subtract(&rhs)
+    }
+}
+
+impl Add<TimeDuration> for PlainTime {
+    type Output = TemporalResult<Self>;
+
+    /// Equivalent to `PlainTime::add_time_duration`.
+    fn add(self, rhs: TimeDuration) -> Self::Output {
+        self.add_time_duration(&rhs)
+    }
+}
+
+impl Sub<TimeDuration> for PlainTime {
+    type Output = TemporalResult<Self>;
+
+    /// Equivalent to `PlainTime::subtract_time_duration`.
+    fn sub(self, rhs: TimeDuration) -> Self::Output {
+        self.subtract_time_duration(&rhs)
+    }
+}
+
+impl Sub<PlainTime> for PlainTime {
+    type Output = TemporalResult<Duration>;
+
+    /// Returns the `Duration` from `rhs` until `self` with the default difference settings,
+    /// balanced up to hours.
+    fn sub(self, rhs: PlainTime) -> Self::Output {
+        rhs.until(&self, DifferenceSettings::default())
+    }
 }
 
 impl From<PlainDateTime> for PlainTime {
@@ -367,7 +623,7 @@ impl FromStr for PlainTime {
     type Err = TemporalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let result = parse_time(s)?;
+        let result = parse_time(s)?.time;
 
         let (millisecond, rem) = (result.nanosecond / 1_000_000, result.nanosecond % 1_000_000);
         let (microsecond, nanosecond) = (rem / 1_000, rem % 1_000);
@@ -389,13 +645,19 @@ impl FromStr for PlainTime {
 #[cfg(test)]
 mod tests {
     use crate::{
-        components::Duration,
+        components::{calendar::Calendar, Duration, PlainDate},
+        error::ErrorKind,
         iso::IsoTime,
-        options::{ArithmeticOverflow, DifferenceSettings, TemporalUnit},
+        options::{
+            ArithmeticOverflow, DifferenceSettings, ResolvedRoundingOptions, RoundingIncrement,
+            RoundingOptions, TemporalRoundingMode, TemporalUnit,
+        },
+        TemporalError, NS_PER_DAY,
     };
+    use core::str::FromStr;
     use num_traits::FromPrimitive;
 
-    use super::PlainTime;
+    use super::{PartialTime, PlainTime};
 
     fn assert_time(result: PlainTime, values: (u8, u8, u8, u16, u16, u16)) {
         assert_eq!(
@@ -439,27 +701,40 @@ mod tests {
         assert_time(result.unwrap(), (12, 5, 24, 123, 456, 789));
     }
 
+    #[test]
+    fn parse_time_rejects_leap_second() {
+        let err = "23:59:60".parse::<PlainTime>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+
+        let err = PlainTime::new_with_overflow(23, 59, 60, 0, 0, 0, ArithmeticOverflow::Reject)
+            .unwrap_err();
+        assert_eq!(err, TemporalError::leap_second());
+
+        let result = "23:59:59.999999999".parse::<PlainTime>();
+        assert_time(result.unwrap(), (23, 59, 59, 999, 999, 999));
+    }
+
     #[test]
     fn time_round_millisecond() {
         let base = PlainTime::new_unchecked(IsoTime::new_unchecked(3, 34, 56, 987, 654, 321));
 
         let result_1 = base
-            .round(TemporalUnit::Millisecond, Some(1.0), None)
+            .round(TemporalUnit::Millisecond, Some(1), None)
             .unwrap();
         assert_time(result_1, (3, 34, 56, 988, 0, 0));
 
         let result_2 = base
-            .round(TemporalUnit::Millisecond, Some(2.0), None)
+            .round(TemporalUnit::Millisecond, Some(2), None)
             .unwrap();
         assert_time(result_2, (3, 34, 56, 988, 0, 0));
 
         let result_3 = base
-            .round(TemporalUnit::Millisecond, Some(4.0), None)
+            .round(TemporalUnit::Millisecond, Some(4), None)
             .unwrap();
         assert_time(result_3, (3, 34, 56, 988, 0, 0));
 
         let result_4 = base
-            .round(TemporalUnit::Millisecond, Some(5.0), None)
+            .round(TemporalUnit::Millisecond, Some(5), None)
             .unwrap();
         assert_time(result_4, (3, 34, 56, 990, 0, 0));
     }
@@ -469,22 +744,22 @@ mod tests {
         let base = PlainTime::new_unchecked(IsoTime::new_unchecked(3, 34, 56, 987, 654, 321));
 
         let result_1 = base
-            .round(TemporalUnit::Microsecond, Some(1.0), None)
+            .round(TemporalUnit::Microsecond, Some(1), None)
             .unwrap();
         assert_time(result_1, (3, 34, 56, 987, 654, 0));
 
         let result_2 = base
-            .round(TemporalUnit::Microsecond, Some(2.0), None)
+            .round(TemporalUnit::Microsecond, Some(2), None)
             .unwrap();
         assert_time(result_2, (3, 34, 56, 987, 654, 0));
 
         let result_3 = base
-            .round(TemporalUnit::Microsecond, Some(4.0), None)
+            .round(TemporalUnit::Microsecond, Some(4), None)
             .unwrap();
         assert_time(result_3, (3, 34, 56, 987, 656, 0));
 
         let result_4 = base
-            .round(TemporalUnit::Microsecond, Some(5.0), None)
+            .round(TemporalUnit::Microsecond, Some(5), None)
             .unwrap();
         assert_time(result_4, (3, 34, 56, 987, 655, 0));
     }
@@ -493,27 +768,109 @@ mod tests {
     fn time_round_nanoseconds() {
         let base = PlainTime::new_unchecked(IsoTime::new_unchecked(3, 34, 56, 987, 654, 321));
 
-        let result_1 = base
-            .round(TemporalUnit::Nanosecond, Some(1.0), None)
-            .unwrap();
+        let result_1 = base.round(TemporalUnit::Nanosecond, Some(1), None).unwrap();
         assert_time(result_1, (3, 34, 56, 987, 654, 321));
 
-        let result_2 = base
-            .round(TemporalUnit::Nanosecond, Some(2.0), None)
-            .unwrap();
+        let result_2 = base.round(TemporalUnit::Nanosecond, Some(2), None).unwrap();
         assert_time(result_2, (3, 34, 56, 987, 654, 322));
 
-        let result_3 = base
-            .round(TemporalUnit::Nanosecond, Some(4.0), None)
-            .unwrap();
+        let result_3 = base.round(TemporalUnit::Nanosecond, Some(4), None).unwrap();
         assert_time(result_3, (3, 34, 56, 987, 654, 320));
 
-        let result_4 = base
-            .round(TemporalUnit::Nanosecond, Some(5.0), None)
-            .unwrap();
+        let result_4 = base.round(TemporalUnit::Nanosecond, Some(5), None).unwrap();
         assert_time(result_4, (3, 34, 56, 987, 654, 320));
     }
 
+    #[test]
+    fn with_overflow_behavior() {
+        let base = PlainTime::new(12, 30, 30, 500, 500, 500).unwrap();
+
+        let cases = [
+            (
+                PartialTime {
+                    hour: Some(25),
+                    ..Default::default()
+                },
+                (23, 30, 30, 500, 500, 500),
+                "hour",
+            ),
+            (
+                PartialTime {
+                    minute: Some(60),
+                    ..Default::default()
+                },
+                (12, 59, 30, 500, 500, 500),
+                "minute",
+            ),
+            (
+                PartialTime {
+                    second: Some(61),
+                    ..Default::default()
+                },
+                (12, 30, 59, 500, 500, 500),
+                "second",
+            ),
+            (
+                PartialTime {
+                    millisecond: Some(1000),
+                    ..Default::default()
+                },
+                (12, 30, 30, 999, 500, 500),
+                "millisecond",
+            ),
+            (
+                PartialTime {
+                    microsecond: Some(1000),
+                    ..Default::default()
+                },
+                (12, 30, 30, 500, 999, 500),
+                "microsecond",
+            ),
+            (
+                PartialTime {
+                    nanosecond: Some(1000),
+                    ..Default::default()
+                },
+                (12, 30, 30, 500, 500, 999),
+                "nanosecond",
+            ),
+        ];
+
+        for (partial, constrained, field) in cases {
+            let result = base
+                .with(partial, Some(ArithmeticOverflow::Constrain))
+                .unwrap();
+            assert_time(result, constrained);
+            // Constrain is the default overflow.
+            assert_eq!(base.with(partial, None).unwrap(), result);
+
+            let err = base
+                .with(partial, Some(ArithmeticOverflow::Reject))
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range);
+            assert!(err.message().starts_with(field), "{}", err.message());
+        }
+    }
+
+    #[test]
+    fn nanoseconds_since_midnight_round_trip() {
+        let cases = [
+            (0, (0, 0, 0, 0, 0, 0)),
+            (1, (0, 0, 0, 0, 0, 1)),
+            (43_200_000_000_000, (12, 0, 0, 0, 0, 0)),
+            (55_410_123_456_789, (15, 23, 30, 123, 456, 789)),
+            (86_399_999_999_999, (23, 59, 59, 999, 999, 999)),
+        ];
+        for (nanoseconds, fields) in cases {
+            let time = PlainTime::from_nanoseconds_since_midnight(nanoseconds).unwrap();
+            assert_time(time, fields);
+            assert_eq!(time.to_nanoseconds_since_midnight(), nanoseconds);
+        }
+
+        assert!(PlainTime::from_nanoseconds_since_midnight(86_400_000_000_000).is_err());
+        assert!(PlainTime::from_nanoseconds_since_midnight(u64::MAX).is_err());
+    }
+
     #[test]
     fn add_duration_basic() {
         let base = PlainTime::new_unchecked(IsoTime::new_unchecked(15, 23, 30, 123, 456, 789));
@@ -522,6 +879,57 @@ mod tests {
         assert_time(result, (7, 23, 30, 123, 456, 789));
     }
 
+    #[test]
+    fn add_with_days_carry() {
+        let base = PlainTime::new(10, 0, 0, 0, 0, 0).unwrap();
+        let (days, result) = base
+            .add_with_days(&"PT25H".parse::<Duration>().unwrap())
+            .unwrap();
+        assert_eq!(days, 1);
+        assert_time(result, (11, 0, 0, 0, 0, 0));
+
+        let (days, result) = base
+            .add_with_days(&"-PT11H".parse::<Duration>().unwrap())
+            .unwrap();
+        assert_eq!(days, -1);
+        assert_time(result, (23, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn subtract_with_days_carry() {
+        let base = PlainTime::new(2, 0, 0, 0, 0, 0).unwrap();
+        let (days, result) = base
+            .subtract_with_days(&"PT5H".parse::<Duration>().unwrap())
+            .unwrap();
+        assert_eq!(days, -1);
+        assert_time(result, (21, 0, 0, 0, 0, 0));
+
+        let (days, result) = base
+            .subtract_with_days(&"PT50H".parse::<Duration>().unwrap())
+            .unwrap();
+        assert_eq!(days, -2);
+        assert_time(result, (0, 0, 0, 0, 0, 0));
+
+        // Subtracting a negative duration carries forward, mirroring `add_with_days`.
+        let (days, result) = base
+            .subtract_with_days(&"-PT23H".parse::<Duration>().unwrap())
+            .unwrap();
+        assert_eq!(days, 1);
+        assert_time(result, (1, 0, 0, 0, 0, 0));
+        assert_eq!(
+            base.subtract(&"PT5H".parse::<Duration>().unwrap()).unwrap(),
+            PlainTime::new(21, 0, 0, 0, 0, 0).unwrap()
+        );
+
+        let err = base
+            .subtract_with_days(&"P1D".parse::<Duration>().unwrap())
+            .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "DateDuration values cannot be subtracted from `Time`."
+        );
+    }
+
     #[test]
     fn since_basic() {
         let one =
@@ -549,6 +957,319 @@ mod tests {
         assert_eq!(result.minutes(), -53.0);
     }
 
+    #[test]
+    fn named_times() {
+        assert_eq!(PlainTime::midnight(), PlainTime::default());
+        assert_time(PlainTime::midnight(), (0, 0, 0, 0, 0, 0));
+        assert_time(PlainTime::noon(), (12, 0, 0, 0, 0, 0));
+        assert_time(PlainTime::MAX, (23, 59, 59, 999, 999, 999));
+        assert!(PlainTime::MAX.is_valid());
+        assert_eq!(
+            PlainTime::MAX.to_nanoseconds_since_midnight(),
+            NS_PER_DAY - 1
+        );
+        assert!(PlainTime::midnight() < PlainTime::noon() && PlainTime::noon() < PlainTime::MAX);
+    }
+
+    #[test]
+    fn round_carries_into_larger_units() {
+        let time = PlainTime::new(0, 59, 40, 0, 0, 0).unwrap();
+        assert_time(
+            time.round(TemporalUnit::Minute, None, None).unwrap(),
+            (1, 0, 0, 0, 0, 0),
+        );
+        assert_time(
+            time.round(TemporalUnit::Minute, Some(15), None).unwrap(),
+            (1, 0, 0, 0, 0, 0),
+        );
+
+        let time = PlainTime::new(9, 59, 59, 999, 999, 500).unwrap();
+        assert_time(
+            time.round(TemporalUnit::Microsecond, None, None).unwrap(),
+            (10, 0, 0, 0, 0, 0),
+        );
+
+        // Rounding past the end of the day wraps around to midnight.
+        let time = PlainTime::new(23, 59, 30, 0, 0, 0).unwrap();
+        assert_time(
+            time.round(TemporalUnit::Minute, None, None).unwrap(),
+            (0, 0, 0, 0, 0, 0),
+        );
+    }
+
+    #[test]
+    fn round_increment_must_be_less_than_wrap_point() {
+        let time = PlainTime::new(17, 5, 24, 0, 0, 0).unwrap();
+        assert_time(
+            time.round(TemporalUnit::Hour, Some(12), None).unwrap(),
+            (12, 0, 0, 0, 0, 0),
+        );
+
+        // Unlike `Instant::round`, an increment of a whole day is rejected.
+        let err = time.round(TemporalUnit::Hour, Some(24), None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert!(time.round(TemporalUnit::Minute, Some(60), None).is_err());
+        assert!(time
+            .round(TemporalUnit::Millisecond, Some(1000), None)
+            .is_err());
+    }
+
+    #[test]
+    fn round_many_matches_round() {
+        let times = [
+            PlainTime::new(0, 0, 0, 0, 0, 0).unwrap(),
+            PlainTime::new(3, 34, 56, 987, 654, 321).unwrap(),
+            PlainTime::new(12, 7, 30, 0, 0, 0).unwrap(),
+            PlainTime::new(23, 59, 59, 999, 999, 999).unwrap(),
+        ];
+        let options = RoundingOptions {
+            smallest_unit: Some(TemporalUnit::Minute),
+            increment: Some(RoundingIncrement::try_new(15).unwrap()),
+            rounding_mode: Some(TemporalRoundingMode::HalfExpand),
+            ..Default::default()
+        };
+        let resolved = ResolvedRoundingOptions::from_options(
+            options,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+        )
+        .unwrap();
+
+        let rounded = PlainTime::round_many(&times, resolved).unwrap();
+        assert_eq!(rounded.len(), times.len());
+        for (time, result) in times.iter().zip(&rounded) {
+            let expected = time
+                .round(
+                    TemporalUnit::Minute,
+                    Some(15),
+                    Some(TemporalRoundingMode::HalfExpand),
+                )
+                .unwrap();
+            assert_eq!(*result, expected);
+        }
+        assert_eq!(rounded[3], PlainTime::default());
+
+        let day = ResolvedRoundingOptions::from_options(
+            RoundingOptions {
+                smallest_unit: Some(TemporalUnit::Day),
+                ..Default::default()
+            },
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Day,
+        )
+        .unwrap();
+        assert!(PlainTime::round_many(&times, day).is_err());
+    }
+
+    #[test]
+    fn arithmetic_operators() {
+        let time = PlainTime::new(23, 30, 0, 0, 0, 0).unwrap();
+        let duration = Duration::from_str("PT45M").unwrap();
+
+        let later = (time + duration).unwrap();
+        assert_time(later, (0, 15, 0, 0, 0, 0));
+        assert_eq!(later, time.add(&duration).unwrap());
+        assert_time((later - duration).unwrap(), (23, 30, 0, 0, 0, 0));
+        assert_time((time + *duration.time()).unwrap(), (0, 15, 0, 0, 0, 0));
+        assert_time((time - *duration.time()).unwrap(), (22, 45, 0, 0, 0, 0));
+
+        // Date units can not be added to a time.
+        assert!((time + Duration::from_str("P1D").unwrap()).is_err());
+
+        let difference = (time - PlainTime::new(1, 15, 30, 0, 0, 0).unwrap()).unwrap();
+        assert_eq!(difference.hours(), 22.0);
+        assert_eq!(difference.minutes(), 14.0);
+        assert_eq!(difference.seconds(), 30.0);
+        let difference = (PlainTime::midnight() - time).unwrap();
+        assert_eq!(difference.hours(), -23.0);
+        assert_eq!(difference.minutes(), -30.0);
+    }
+
+    #[test]
+    fn nanoseconds_of_day_sort_key() {
+        assert_eq!(PlainTime::MAX.nanoseconds_of_day(), 86_399_999_999_999);
+        assert_eq!(PlainTime::midnight().nanoseconds_of_day(), 0);
+
+        let times = [
+            PlainTime::new(0, 0, 0, 0, 0, 1).unwrap(),
+            PlainTime::new(0, 0, 0, 0, 1, 0).unwrap(),
+            PlainTime::new(0, 0, 1, 0, 0, 0).unwrap(),
+            PlainTime::new(12, 30, 15, 123, 456, 789).unwrap(),
+            PlainTime::new(12, 30, 15, 123, 456, 790).unwrap(),
+            PlainTime::new(23, 0, 0, 0, 0, 0).unwrap(),
+        ];
+        for time in times {
+            let expected = ((((u64::from(time.hour()) * 60 + u64::from(time.minute())) * 60
+                + u64::from(time.second()))
+                * 1000
+                + u64::from(time.millisecond()))
+                * 1000
+                + u64::from(time.microsecond()))
+                * 1000
+                + u64::from(time.nanosecond());
+            assert_eq!(time.nanoseconds_of_day(), expected);
+            assert_eq!(time.to_nanoseconds_since_midnight(), expected);
+        }
+        for pair in times.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].nanoseconds_of_day() < pair[1].nanoseconds_of_day());
+        }
+    }
+
+    #[test]
+    fn diff_of_equal_times_is_positive_zero() {
+        let assert_positive_zero = |duration: Duration| {
+            assert!(duration.is_zero());
+            assert!(duration
+                .fields()
+                .iter()
+                .all(|field| field.as_inner() == 0.0 && field.as_inner().is_sign_positive()));
+        };
+
+        let time = PlainTime::new(12, 34, 56, 987, 654, 321).unwrap();
+        let mut settings = DifferenceSettings::default();
+        assert_positive_zero(time.since(&time, settings).unwrap());
+        assert_positive_zero(time.until(&time, settings).unwrap());
+
+        settings.smallest_unit = Some(TemporalUnit::Minute);
+        for mode in [TemporalRoundingMode::Ceil, TemporalRoundingMode::Floor] {
+            settings.rounding_mode = Some(mode);
+            assert_positive_zero(time.since(&time, settings).unwrap());
+            assert_positive_zero(time.until(&time, settings).unwrap());
+            let (since, until) = time.diff_both(&time, settings).unwrap();
+            assert_positive_zero(since);
+            assert_positive_zero(until);
+        }
+
+        // A non-zero difference that rounds away to zero is also a positive zero.
+        let later = PlainTime::new(12, 34, 56, 987, 654, 322).unwrap();
+        settings.rounding_mode = Some(TemporalRoundingMode::Trunc);
+        assert_positive_zero(time.since(&later, settings).unwrap());
+        assert_positive_zero(later.since(&time, settings).unwrap());
+        assert_positive_zero(time.until(&later, settings).unwrap());
+        assert_positive_zero(later.until(&time, settings).unwrap());
+    }
+
+    #[test]
+    fn directed_rounding_of_negative_differences() {
+        let earlier = PlainTime::new(12, 0, 0, 0, 0, 0).unwrap();
+        let later = PlainTime::new(12, 1, 30, 0, 0, 0).unwrap();
+
+        // (mode, rounded -PT1M30S, rounded PT1M30S)
+        let cases = [
+            (TemporalRoundingMode::Ceil, -1.0, 2.0),
+            (TemporalRoundingMode::Floor, -2.0, 1.0),
+            (TemporalRoundingMode::Trunc, -1.0, 1.0),
+            (TemporalRoundingMode::Expand, -2.0, 2.0),
+        ];
+
+        let mut settings = DifferenceSettings::default();
+        settings.smallest_unit = Some(TemporalUnit::Minute);
+        for (mode, negative, positive) in cases {
+            settings.rounding_mode = Some(mode);
+
+            let result = earlier.since(&later, settings).unwrap();
+            assert_eq!(result.minutes(), negative, "{mode:?} since");
+            assert_eq!(result.seconds(), 0.0);
+            let result = later.until(&earlier, settings).unwrap();
+            assert_eq!(result.minutes(), negative, "{mode:?} until");
+
+            let result = later.since(&earlier, settings).unwrap();
+            assert_eq!(result.minutes(), positive, "{mode:?} since");
+            let result = earlier.until(&later, settings).unwrap();
+            assert_eq!(result.minutes(), positive, "{mode:?} until");
+        }
+    }
+
+    #[test]
+    fn diff_both_matches_since_and_until() {
+        let one =
+            PlainTime::new_with_overflow(15, 23, 30, 123, 456, 789, ArithmeticOverflow::Constrain)
+                .unwrap();
+        let two =
+            PlainTime::new_with_overflow(13, 30, 30, 123, 456, 789, ArithmeticOverflow::Constrain)
+                .unwrap();
+
+        let mut settings = DifferenceSettings::default();
+        settings.smallest_unit = Some(TemporalUnit::Hour);
+
+        let (since, until) = one.diff_both(&two, settings).unwrap();
+        assert_eq!(since.fields(), until.negated().fields());
+        assert_eq!(since.fields(), one.since(&two, settings).unwrap().fields());
+        assert_eq!(until.fields(), one.until(&two, settings).unwrap().fields());
+        assert_eq!(until.hours(), -2.0);
+
+        // Directed rounding modes round `since` with the negated mode.
+        for mode in [TemporalRoundingMode::Floor, TemporalRoundingMode::HalfCeil] {
+            settings.rounding_mode = Some(mode);
+            let (since, until) = one.diff_both(&two, settings).unwrap();
+            assert_eq!(since.fields(), one.since(&two, settings).unwrap().fields());
+            assert_eq!(until.fields(), one.until(&two, settings).unwrap().fields());
+        }
+    }
+
+    #[test]
+    fn until_resolved_auto_largest_unit() {
+        let one = PlainTime::new(9, 0, 0, 0, 0, 0).unwrap();
+        let two = PlainTime::new(10, 30, 0, 0, 0, 0).unwrap();
+
+        let mut settings = DifferenceSettings::default();
+        settings.largest_unit = Some(TemporalUnit::Auto);
+        let (result, resolved) = one.until_resolved(&two, settings).unwrap();
+        assert_eq!(resolved.largest_unit(), TemporalUnit::Hour);
+        assert_eq!(resolved.smallest_unit(), TemporalUnit::Nanosecond);
+        assert_eq!(result.hours(), 1.0);
+        assert_eq!(result.minutes(), 30.0);
+
+        settings.smallest_unit = Some(TemporalUnit::Minute);
+        settings.largest_unit = Some(TemporalUnit::Auto);
+        let (result, resolved) = one.until_resolved(&two, settings).unwrap();
+        assert_eq!(resolved.largest_unit(), TemporalUnit::Hour);
+        assert_eq!(resolved.smallest_unit(), TemporalUnit::Minute);
+        assert_eq!(result.minutes(), 30.0);
+    }
+
+    #[test]
+    fn until_with_settings_builder() {
+        let one = PlainTime::new(9, 0, 0, 0, 0, 0).unwrap();
+        let two = PlainTime::new(10, 30, 40, 0, 0, 0).unwrap();
+
+        let settings = DifferenceSettings::new()
+            .largest_unit(TemporalUnit::Minute)
+            .smallest_unit(TemporalUnit::Minute)
+            .rounding_mode(TemporalRoundingMode::Ceil);
+        let result = one.until(&two, settings).unwrap();
+        assert_eq!(result.hours(), 0.0);
+        assert_eq!(result.minutes(), 91.0);
+
+        let settings = DifferenceSettings::new()
+            .largest_unit(TemporalUnit::Minute)
+            .smallest_unit(TemporalUnit::Hour);
+        assert!(one.until(&two, settings).is_err());
+    }
+
+    #[test]
+    fn diff_rejects_inverted_units() {
+        let one = PlainTime::new(9, 0, 0, 0, 0, 0).unwrap();
+        let two = PlainTime::new(10, 30, 40, 0, 0, 0).unwrap();
+
+        let settings = DifferenceSettings::new()
+            .largest_unit(TemporalUnit::Second)
+            .smallest_unit(TemporalUnit::Hour);
+        let err = one.until(&two, settings).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(
+            err.message(),
+            "largestUnit \"second\" cannot be smaller than smallestUnit \"hour\"."
+        );
+        assert!(one.since(&two, settings).is_err());
+
+        // An explicit largest unit smaller than the default is still valid.
+        let settings = DifferenceSettings::new().largest_unit(TemporalUnit::Second);
+        let result = one.until(&two, settings).unwrap();
+        assert_eq!(result.seconds(), 5440.0);
+    }
+
     #[test]
     fn until_basic() {
         let one =
@@ -584,94 +1305,375 @@ mod tests {
                 .unwrap();
 
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(1.0), None)
-                .unwrap(),
+            time.round(TemporalUnit::Nanosecond, Some(1), None).unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 321, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(2.0), None)
-                .unwrap(),
+            time.round(TemporalUnit::Nanosecond, Some(2), None).unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 322, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(4.0), None)
-                .unwrap(),
+            time.round(TemporalUnit::Nanosecond, Some(4), None).unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(5.0), None)
-                .unwrap(),
+            time.round(TemporalUnit::Nanosecond, Some(5), None).unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(8.0), None)
-                .unwrap(),
+            time.round(TemporalUnit::Nanosecond, Some(8), None).unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(10.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(10), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(20.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(20), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(25.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(25), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 325, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(40.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(40), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 320, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(50.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(50), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 300, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(100.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(100), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 300, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(125.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(125), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 375, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(200.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(200), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 400, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(250.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(250), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 250, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
         assert_eq!(
-            time.round(TemporalUnit::Nanosecond, Some(500.0), None)
+            time.round(TemporalUnit::Nanosecond, Some(500), None)
                 .unwrap(),
             PlainTime::new_with_overflow(3, 34, 56, 987, 654, 500, ArithmeticOverflow::Constrain)
                 .unwrap()
         );
     }
+
+    #[test]
+    fn rounding_increment_validation() {
+        let time = PlainTime::new(3, 34, 56, 987, 654, 321).unwrap();
+        assert!(time.round(TemporalUnit::Nanosecond, Some(0), None).is_err());
+        assert!(time.round(TemporalUnit::Nanosecond, Some(3), None).is_err());
+        assert!(time
+            .round(TemporalUnit::Nanosecond, Some(1000), None)
+            .is_err());
+        assert_eq!(
+            time.round(TemporalUnit::Second, None, None).unwrap(),
+            PlainTime::new(3, 34, 57, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolved_rounding_options_from_options() {
+        let options = RoundingOptions {
+            largest_unit: None,
+            smallest_unit: None,
+            rounding_mode: None,
+            increment: None,
+        };
+        let resolved =
+            ResolvedRoundingOptions::from_options(options, TemporalUnit::Minute, TemporalUnit::Day)
+                .unwrap();
+        assert_eq!(resolved.smallest_unit(), TemporalUnit::Minute);
+        assert_eq!(resolved.largest_unit(), TemporalUnit::Day);
+        assert_eq!(resolved.increment(), RoundingIncrement::ONE);
+        assert!(matches!(
+            resolved.rounding_mode(),
+            TemporalRoundingMode::HalfExpand
+        ));
+
+        let options = RoundingOptions {
+            largest_unit: Some(TemporalUnit::Auto),
+            smallest_unit: Some(TemporalUnit::Hour),
+            rounding_mode: Some(TemporalRoundingMode::Floor),
+            increment: Some(RoundingIncrement::try_new(6).unwrap()),
+        };
+        let resolved = ResolvedRoundingOptions::from_options(
+            options,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Minute,
+        )
+        .unwrap();
+        assert_eq!(resolved.largest_unit(), TemporalUnit::Hour);
+
+        let invalid_increment = RoundingOptions {
+            increment: Some(RoundingIncrement::try_new(24).unwrap()),
+            ..options
+        };
+        assert!(ResolvedRoundingOptions::from_options(
+            invalid_increment,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Minute
+        )
+        .is_err());
+
+        let inverted = RoundingOptions {
+            largest_unit: Some(TemporalUnit::Minute),
+            ..options
+        };
+        assert!(ResolvedRoundingOptions::from_options(
+            inverted,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Minute
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rounding_increment_maxima() {
+        let time = PlainTime::new(3, 34, 56, 987, 654, 321).unwrap();
+        let units = [
+            (TemporalUnit::Hour, 24, 12, 5),
+            (TemporalUnit::Minute, 60, 30, 7),
+            (TemporalUnit::Second, 60, 30, 7),
+            (TemporalUnit::Millisecond, 1000, 500, 3),
+            (TemporalUnit::Microsecond, 1000, 500, 3),
+            (TemporalUnit::Nanosecond, 1000, 500, 3),
+        ];
+        for (unit, max, divisor, non_divisor) in units {
+            assert_eq!(unit.to_maximum_rounding_increment(), Some(max));
+            assert!(time.round(unit, Some(1), None).is_ok());
+            assert!(time.round(unit, Some(divisor), None).is_ok());
+            // The maximum is exclusive for time units.
+            assert!(time.round(unit, Some(max), None).is_err());
+            assert!(time.round(unit, Some(max * 2), None).is_err());
+            assert!(time.round(unit, Some(non_divisor), None).is_err());
+        }
+
+        for unit in [TemporalUnit::Day, TemporalUnit::Auto] {
+            assert_eq!(unit.to_maximum_rounding_increment(), None);
+            assert!(time.round(unit, None, None).is_err());
+        }
+    }
+
+    #[test]
+    fn iso_time_public_api() {
+        let start = IsoTime::new(10, 0, 0, 0, 0, 0, ArithmeticOverflow::Reject).unwrap();
+        let end = IsoTime::new(9, 30, 0, 0, 0, 0, ArithmeticOverflow::Reject).unwrap();
+        let diff = start.diff(&end);
+        assert_eq!(diff.hours, -1.0);
+        assert_eq!(diff.minutes, 30.0);
+
+        let partial = PartialTime {
+            minute: Some(75),
+            ..Default::default()
+        };
+        let constrained = start.with(partial, ArithmeticOverflow::Constrain).unwrap();
+        assert!(constrained.is_valid());
+        assert_eq!((constrained.hour(), constrained.minute()), (10, 59));
+        assert!(start.with(partial, ArithmeticOverflow::Reject).is_err());
+
+        let (days, balanced) = IsoTime::balance(-1, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(days, -1);
+        assert!(balanced.is_valid());
+        assert_eq!(balanced.hour(), 23);
+    }
+
+    #[test]
+    fn new_with_negative_fields() {
+        let fields = |time: PlainTime| {
+            [
+                u16::from(time.hour()),
+                u16::from(time.minute()),
+                u16::from(time.second()),
+                time.millisecond(),
+                time.microsecond(),
+                time.nanosecond(),
+            ]
+        };
+        for index in 0..6 {
+            let mut values = [12, 30, 30, 500, 500, 500];
+            values[index] = -1;
+            let [hour, minute, second, millisecond, microsecond, nanosecond] = values;
+
+            let constrained = PlainTime::new_with_overflow(
+                hour,
+                minute,
+                second,
+                millisecond,
+                microsecond,
+                nanosecond,
+                ArithmeticOverflow::Constrain,
+            )
+            .unwrap();
+            let mut expected = [12, 30, 30, 500, 500, 500];
+            expected[index] = 0;
+            assert_eq!(fields(constrained), expected);
+
+            let rejected = PlainTime::new_with_overflow(
+                hour,
+                minute,
+                second,
+                millisecond,
+                microsecond,
+                nanosecond,
+                ArithmeticOverflow::Reject,
+            );
+            assert!(rejected.is_err());
+        }
+
+        let time = PlainTime::new(0, 0, 0, 0, i32::MIN, 0).unwrap();
+        assert_eq!(fields(time), [0; 6]);
+    }
+
+    #[test]
+    fn hash_set_lookup() {
+        use std::collections::HashSet;
+
+        let mut times = HashSet::new();
+        assert!(times.insert(PlainTime::new(12, 30, 0, 0, 0, 0).unwrap()));
+        assert!(times.insert(PlainTime::new(12, 30, 0, 0, 0, 1).unwrap()));
+        // An equal time constructed differently hashes the same.
+        assert!(!times.insert(PlainTime::from_str("12:30").unwrap()));
+        assert!(times.contains(&PlainTime::new(12, 30, 0, 0, 0, 1).unwrap()));
+        assert_eq!(times.len(), 2);
+    }
+
+    #[test]
+    fn round_half_even() {
+        let round = |microsecond, nanosecond| {
+            PlainTime::new(0, 0, 0, 0, microsecond, nanosecond)
+                .unwrap()
+                .round(
+                    TemporalUnit::Microsecond,
+                    None,
+                    Some(TemporalRoundingMode::HalfEven),
+                )
+                .unwrap()
+        };
+        assert_time(round(2, 500), (0, 0, 0, 0, 2, 0));
+        assert_time(round(3, 500), (0, 0, 0, 0, 4, 0));
+        assert_time(round(3, 501), (0, 0, 0, 0, 4, 0));
+        assert_time(round(2, 499), (0, 0, 0, 0, 2, 0));
+    }
+
+    #[test]
+    fn round_hour_and_minute_increment_boundaries() {
+        let time = PlainTime::new(13, 46, 23, 0, 0, 0).unwrap();
+        assert_time(
+            time.round(TemporalUnit::Hour, Some(12), None).unwrap(),
+            (12, 0, 0, 0, 0, 0),
+        );
+        assert_time(
+            time.round(TemporalUnit::Minute, Some(30), None).unwrap(),
+            (14, 0, 0, 0, 0, 0),
+        );
+
+        let err = time.round(TemporalUnit::Hour, Some(24), None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert!(err.message().contains("23"));
+        let err = time
+            .round(TemporalUnit::Minute, Some(60), None)
+            .unwrap_err();
+        assert!(err.message().contains("59"));
+    }
+
+    /// A xorshift generator, so that the randomized balance tests are reproducible.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn balance_beyond_f64_precision() {
+        // 2^53 + 1 is not representable as an f64.
+        let (days, time) = IsoTime::balance(0, 0, 0, 0, 0, 9_007_199_254_740_993).unwrap();
+        assert_eq!(days, 104);
+        assert_eq!(time, IsoTime::new_unchecked(5, 59, 59, 254, 740, 993));
+
+        let (days, time) = IsoTime::balance(0, 0, 0, 0, 0, -9_007_199_254_740_993).unwrap();
+        assert_eq!(days, -105);
+        assert_eq!(time, IsoTime::new_unchecked(18, 0, 0, 745, 259, 7));
+
+        // Totals beyond `i128`, or days beyond `i32`, are rejected rather than saturated.
+        assert!(IsoTime::balance(i128::MAX, 0, 0, 0, 0, 0).is_err());
+        assert!(IsoTime::balance(0, 0, 0, 0, -1, i128::MIN).is_err());
+        assert!(IsoTime::balance(24 * (i128::from(i32::MAX) + 1), 0, 0, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn balance_round_trips_random_large_inputs() {
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..10_000 {
+            // Any `i64` of nanoseconds, which is roughly ±106,751 days.
+            let total = i128::from(xorshift(&mut state) as i64);
+            let (days, time) = IsoTime::balance(0, 0, 0, 0, 0, total).unwrap();
+            assert!(time.is_valid());
+
+            let unbalanced = i128::from(days) * i128::from(NS_PER_DAY)
+                + i128::from(PlainTime::new_unchecked(time).to_nanoseconds_since_midnight());
+            assert_eq!(unbalanced, total);
+
+            // Spreading the total across the fields balances to the same time.
+            let (hours, remainder) = (total / 3_600_000_000_000, total % 3_600_000_000_000);
+            let (seconds, nanoseconds) = (remainder / 1_000_000_000, remainder % 1_000_000_000);
+            assert_eq!(
+                IsoTime::balance(hours, 0, seconds, 0, 0, nanoseconds).unwrap(),
+                (days, time)
+            );
+        }
+    }
+
+    #[test]
+    fn to_plain_date_time_uses_date_calendar() {
+        let time = PlainTime::try_new(13, 37, 5, 1, 2, 3).unwrap();
+        let calendar = Calendar::from_str("gregory").unwrap();
+        let date = PlainDate::try_new(2024, 5, 4, calendar.clone()).unwrap();
+
+        let date_time = time.to_plain_date_time(&date).unwrap();
+        assert_eq!(date_time.calendar(), &calendar);
+        assert_eq!(
+            (
+                date_time.iso_year(),
+                date_time.iso_month(),
+                date_time.iso_day()
+            ),
+            (2024, 5, 4)
+        );
+        assert_eq!(PlainTime::from(date_time), time);
+    }
 }
@@ -5,7 +5,8 @@ use num_traits::ToPrimitive;
 
 use crate::{
     components::{calendar::Calendar, Instant, PlainDateTime},
-    TemporalError, TemporalResult,
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    TemporalError, TemporalResult, TemporalUnwrap,
 };
 
 /// A Temporal `TimeZone`.
@@ -22,7 +23,11 @@ impl TimeZone {
         calendar: &Calendar,
     ) -> TemporalResult<PlainDateTime> {
         let nanos = self.get_offset_nanos_for()?;
-        PlainDateTime::from_instant(instant, nanos.to_f64().unwrap_or(0.0), calendar.clone())
+        PlainDateTime::from_instant_with_calendar(
+            instant,
+            nanos.to_i128().temporal_unwrap()?,
+            calendar.clone(),
+        )
     }
 }
 
@@ -40,9 +45,59 @@ impl TimeZone {
         Err(TemporalError::range().with_message("IANA TimeZone names not yet implemented."))
     }
 
-    /// Get the possible `Instant`s for this `TimeZoneSlot`.
-    pub fn get_possible_instant_for(&self) -> TemporalResult<Vec<Instant>> {
-        Err(TemporalError::general("Not yet implemented."))
+    /// Get the possible `Instant`s for the provided `PlainDateTime` in this `TimeZoneSlot`.
+    pub fn get_possible_instant_for(
+        &self,
+        date_time: &PlainDateTime,
+    ) -> TemporalResult<Vec<Instant>> {
+        // 1. If timeZone.[[OffsetMinutes]] is not empty, then
+        if let Some(offset) = self.offset {
+            // a. Let epochNanoseconds be GetUTCEpochNanoseconds(isoDateTime) - offsetMinutes × (60 × 10^9).
+            // b. Let possibleEpochNanoseconds be « epochNanoseconds ».
+            return Ok(vec![date_time.to_instant_with_offset(offset.into())?]);
+        }
+        // 2. Else,
+        // a. Let possibleEpochNanoseconds be GetNamedTimeZoneEpochNanoseconds(timeZone.[[Identifier]], isoDateTime).
+        Err(TemporalError::range().with_message("IANA TimeZone names not yet implemented."))
+    }
+
+    /// Returns the `Instant` of the provided wall-clock `PlainDateTime` in this `TimeZoneSlot`.
+    ///
+    /// Equivalent to `GetEpochNanosecondsFor` with a `compatible` disambiguation.
+    // TODO: Handle the `compatible` disambiguation of wall-clock times that fall into a gap.
+    pub(crate) fn get_epoch_nanoseconds_for(
+        &self,
+        date_time: &PlainDateTime,
+    ) -> TemporalResult<Instant> {
+        self.get_possible_instant_for(date_time)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                TemporalError::range()
+                    .with_message("Wall-clock times in a time zone gap are not yet supported.")
+            })
+    }
+
+    /// Returns the first `Instant` of the provided ISO date in this `TimeZoneSlot`.
+    ///
+    /// Equivalent to `GetStartOfDay`.
+    pub(crate) fn get_start_of_day(&self, date: IsoDate) -> TemporalResult<Instant> {
+        // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, MidnightTimeRecord()).
+        let date_time = PlainDateTime::new_unchecked(
+            IsoDateTime::new(date, IsoTime::default())?,
+            Calendar::default(),
+        );
+        // 2. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        // 3. If possibleEpochNs is not empty, return possibleEpochNs[0].
+        // 4. Assert: IsOffsetTimeZoneIdentifier(timeZone) is false.
+        // TODO: 5-9. Handle a midnight that falls into a gap once IANA time zones are supported.
+        self.get_possible_instant_for(&date_time)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                TemporalError::range()
+                    .with_message("Wall-clock times in a time zone gap are not yet supported.")
+            })
     }
 
     /// Returns the current `TimeZoneSlot`'s identifier.
@@ -50,3 +105,24 @@ impl TimeZone {
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::components::{calendar::Calendar, PlainDateTime};
+
+    use super::TimeZone;
+
+    #[test]
+    fn offset_possible_instants() {
+        let tz = TimeZone {
+            iana: None,
+            offset: Some(-300),
+        };
+        let date_time =
+            PlainDateTime::new(2024, 3, 10, 2, 30, 0, 0, 0, 0, Calendar::default()).unwrap();
+        let instants = tz.get_possible_instant_for(&date_time).unwrap();
+        assert_eq!(instants.len(), 1);
+        // 2024-03-10T07:30:00Z
+        assert_eq!(instants[0].epoch_nanos, 1_710_055_800_000_000_000);
+    }
+}
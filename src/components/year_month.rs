@@ -1,6 +1,6 @@
 //! This module implements `YearMonth` and any directly related algorithms.
 
-use std::str::FromStr;
+use core::{cmp::Ordering, fmt, str::FromStr};
 
 use tinystr::TinyAsciiStr;
 
@@ -44,9 +44,36 @@ impl PlainYearMonth {
     ) -> TemporalResult<Self> {
         let day = reference_day.unwrap_or(1);
         let iso = IsoDate::new_with_overflow(year, month, day, overflow)?;
+        if !year_month_within_limits(iso.year, iso.month) {
+            return Err(
+                TemporalError::range().with_message("PlainYearMonth is not within a valid range.")
+            );
+        }
         Ok(Self::new_unchecked(iso, calendar))
     }
 
+    /// Returns the earliest representable ISO `PlainYearMonth`, `-271821-04`.
+    #[inline]
+    #[must_use]
+    pub fn min() -> Self {
+        Self::new_unchecked(IsoDate::new_unchecked(-271_821, 4, 1), Calendar::default())
+    }
+
+    /// Returns the latest representable ISO `PlainYearMonth`, `+275760-09`.
+    #[inline]
+    #[must_use]
+    pub fn max() -> Self {
+        Self::new_unchecked(IsoDate::new_unchecked(275_760, 9, 1), Calendar::default())
+    }
+
+    /// Compares two `PlainYearMonth`s by their ISO reference dates, i.e. by year and then by
+    /// month, ignoring their calendars.
+    #[inline]
+    #[must_use]
+    pub fn compare(one: &Self, two: &Self) -> Ordering {
+        one.iso.cmp(&two.iso)
+    }
+
     /// Returns the iso year value for this `YearMonth`.
     #[inline]
     #[must_use]
@@ -177,6 +204,16 @@ impl IsoDateSlots for PlainYearMonth {
     }
 }
 
+/// Equivalent to `ISOYearMonthWithinLimits`.
+#[inline]
+fn year_month_within_limits(year: i32, month: u8) -> bool {
+    match year {
+        -271_821 => month >= 4,
+        275_760 => month <= 9,
+        _ => (-271_821..=275_760).contains(&year),
+    }
+}
+
 impl FromStr for PlainYearMonth {
     type Err = TemporalError;
 
@@ -196,3 +233,67 @@ impl FromStr for PlainYearMonth {
         )
     }
 }
+
+impl fmt::Display for PlainYearMonth {
+    /// Formats the `YearMonth` as `YYYY-MM`, or with the reference ISO day and a calendar
+    /// annotation when the calendar is not `iso8601`, e.g. `2024-12-01[u-ca=gregory]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", pad_iso_year(self.iso.year), self.iso.month)?;
+        if self.calendar.is_iso() {
+            return Ok(());
+        }
+        write!(
+            f,
+            "-{:02}[u-ca={}]",
+            self.iso.day,
+            self.calendar.identifier()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{cmp::Ordering, str::FromStr};
+
+    use super::PlainYearMonth;
+
+    #[test]
+    fn year_month_from_str_and_display() {
+        let ym = PlainYearMonth::from_str("2024-12").unwrap();
+        assert_eq!((ym.iso_year(), ym.iso_month()), (2024, 12));
+        assert_eq!(ym.to_string(), "2024-12");
+
+        let ym = PlainYearMonth::from_str("+012345-06[u-ca=iso8601]").unwrap();
+        assert_eq!(ym.to_string(), "+012345-06");
+
+        // A day component is not a valid part of a `YearMonth` string.
+        assert!(PlainYearMonth::from_str("2024-12-25").is_err());
+        assert!(PlainYearMonth::from_str("2024-12[u-ca=gregory]").is_err());
+        assert!(PlainYearMonth::from_str("2024-13").is_err());
+    }
+
+    #[test]
+    fn compare_and_limits() {
+        let ym = |s| PlainYearMonth::from_str(s).unwrap();
+        let (jan, feb, next_jan) = (ym("2024-01"), ym("2024-02"), ym("2025-01"));
+        assert_eq!(PlainYearMonth::compare(&jan, &feb), Ordering::Less);
+        assert_eq!(PlainYearMonth::compare(&feb, &next_jan), Ordering::Less);
+        assert_eq!(PlainYearMonth::compare(&next_jan, &jan), Ordering::Greater);
+        assert_eq!(
+            PlainYearMonth::compare(&feb, &ym("2024-02")),
+            Ordering::Equal
+        );
+
+        let mut months = [next_jan, jan, feb];
+        months.sort_by(PlainYearMonth::compare);
+        let months = months.map(|ym| ym.to_string());
+        assert_eq!(months, ["2024-01", "2024-02", "2025-01"]);
+
+        assert_eq!(PlainYearMonth::min().to_string(), "-271821-04");
+        assert_eq!(PlainYearMonth::max().to_string(), "+275760-09");
+        assert_eq!(ym("-271821-04").to_string(), "-271821-04");
+        assert_eq!(ym("+275760-09").to_string(), "+275760-09");
+        assert!(PlainYearMonth::from_str("-271821-03").is_err());
+        assert!(PlainYearMonth::from_str("+275760-10").is_err());
+    }
+}
@@ -1,10 +1,31 @@
 //! This module implements `ZonedDateTime` and any directly related algorithms.
 
-use tinystr::TinyStr4;
+use core::{num::NonZeroU128, str::FromStr};
+
+use ixdtf::parsers::records::TimeZoneRecord;
+use num_traits::ToPrimitive;
+use tinystr::{TinyAsciiStr, TinyStr4};
 
 use crate::{
-    components::{calendar::Calendar, tz::TimeZone, Instant},
-    TemporalResult,
+    components::{
+        calendar::Calendar,
+        duration::{
+            normalized::{NormalizedDurationRecord, NormalizedTimeDuration, RelativeRoundResult},
+            DateDuration, TimeDuration,
+        },
+        tz::TimeZone,
+        Duration, Instant, PlainDate, PlainDateTime,
+    },
+    iso::{IsoDate, IsoDateTime},
+    options::{
+        ArithmeticOverflow, Precision, ResolvedRoundingOptions, RoundingIncrement, RoundingOptions,
+        TemporalUnit,
+    },
+    parsers::{parse_zoned_date_time, utc_offset_nanoseconds},
+    primitive::FiniteF64,
+    rounding::{IncrementRounder, Round},
+    utils::format_fractional_seconds,
+    Sign, TemporalError, TemporalResult, TemporalUnwrap,
 };
 
 use super::calendar::CalendarDateLike;
@@ -19,13 +40,13 @@ pub struct ZonedDateTime {
 }
 
 impl Ord for ZonedDateTime {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.instant.cmp(&other.instant)
     }
 }
 
 impl PartialOrd for ZonedDateTime {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -43,6 +64,184 @@ impl ZonedDateTime {
             tz,
         }
     }
+
+    /// Adds a `Duration` to the current `ZonedDateTime`, returning the resulting `Instant`.
+    ///
+    /// Temporal Equivalent: `AddZonedDateTime ( epochNanoseconds, timeZone, calendar, duration, overflow )`
+    pub(crate) fn add_as_instant(
+        &self,
+        duration: &Duration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Instant> {
+        // 1. If DateDurationSign(duration.[[Date]]) = 0, then
+        if duration.date().sign() == Sign::Zero {
+            // a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
+            return self.instant.add_to_instant(duration.time());
+        }
+
+        // 2. Let isoDateTime be GetISODateTimeFor(timeZone, epochNanoseconds).
+        let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+        // 3. Let addedDate be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], overflow).
+        let added_date = PlainDate::new_unchecked(dt.iso.date, self.calendar.clone())
+            .add_date(&Duration::from(*duration.date()), Some(overflow))?;
+        // 4. Let intermediateDateTime be CombineISODateAndTimeRecord(addedDate, isoDateTime.[[Time]]).
+        let intermediate = PlainDateTime::new_unchecked(
+            IsoDateTime::new_unchecked(added_date.iso, dt.iso.time),
+            self.calendar.clone(),
+        );
+        // 5. If ISODateTimeWithinLimits(intermediateDateTime) is false, throw a RangeError exception.
+        if !intermediate.iso.is_within_limits() {
+            return Err(
+                TemporalError::range().with_message("Intermediate date time is not within limits.")
+            );
+        }
+        // 6. Let intermediateNs be ! GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+        let intermediate_ns = self.tz.get_epoch_nanoseconds_for(&intermediate)?;
+        // 7. Return ? AddInstant(intermediateNs, duration.[[Time]]).
+        intermediate_ns.add_to_instant(duration.time())
+    }
+
+    /// Returns the difference between this `ZonedDateTime` and `other`, with days resolved as
+    /// calendar days in the time zone and the time difference limited to less than a day.
+    ///
+    /// Temporal Equivalent: `DifferenceZonedDateTime ( ns1, ns2, timeZone, calendar, largestUnit )`
+    pub(crate) fn diff_instant(
+        &self,
+        other: &Instant,
+        largest_unit: TemporalUnit,
+    ) -> TemporalResult<NormalizedDurationRecord> {
+        // 1. If ns1 = ns2, return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+        if self.instant == *other {
+            return NormalizedDurationRecord::from_date_duration(DateDuration::default());
+        }
+        // NOTE: As in `DifferenceZonedDateTimeWithRounding`, time units are differenced in exact time.
+        if largest_unit.is_time_unit() {
+            let norm = NormalizedTimeDuration::from_nanosecond_difference(
+                other.epoch_nanos,
+                self.instant.epoch_nanos,
+            )?;
+            return NormalizedDurationRecord::new(DateDuration::default(), norm);
+        }
+        // 2. Let startDateTime be GetISODateTimeFor(timeZone, ns1).
+        let start = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+        // 3. Let endDateTime be GetISODateTimeFor(timeZone, ns2).
+        let end = self.tz.get_datetime_for(other, &self.calendar)?;
+        // 4. If CompareISODate(startDateTime.[[ISODate]], endDateTime.[[ISODate]]) = 0, then
+        if start.iso.date == end.iso.date {
+            // a. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+            let norm = NormalizedTimeDuration::from_nanosecond_difference(
+                other.epoch_nanos,
+                self.instant.epoch_nanos,
+            )?;
+            // b. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+            return NormalizedDurationRecord::new(DateDuration::default(), norm);
+        }
+        // 5. If ns2 - ns1 < 0, let sign be -1; else let sign be 1.
+        let sign = if other.epoch_nanos < self.instant.epoch_nanos {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        // 6. If sign = 1, let maxDayCorrection be 2; else let maxDayCorrection be 1.
+        let max_day_correction = if sign == Sign::Positive { 2 } else { 1 };
+        // 7. Let dayCorrection be 0.
+        let mut day_correction = 0;
+        // 8. Let timeDuration be DifferenceTime(startDateTime.[[Time]], endDateTime.[[Time]]).
+        let time_duration =
+            NormalizedTimeDuration::from_time_duration(&start.iso.time.diff(&end.iso.time));
+        // 9. If TimeDurationSign(timeDuration) = -sign, set dayCorrection to dayCorrection + 1.
+        if time_duration.sign() == sign.negate() {
+            day_correction += 1;
+        }
+        // 10. Let success be false.
+        let mut success = None;
+        // 11. Repeat, while dayCorrection ≤ maxDayCorrection and success is false,
+        while day_correction <= max_day_correction && success.is_none() {
+            // a. Let intermediateDate be BalanceISODate(endDateTime.[[ISODate]].[[Year]],
+            // endDateTime.[[ISODate]].[[Month]], endDateTime.[[ISODate]].[[Day]] - dayCorrection × sign).
+            let intermediate_date = IsoDate::balance(
+                end.iso.date.year,
+                i32::from(end.iso.date.month),
+                i32::from(end.iso.date.day) - day_correction * i32::from(sign as i8),
+            );
+            // b. Let intermediateDateTime be CombineISODateAndTimeRecord(intermediateDate, startDateTime.[[Time]]).
+            let intermediate = PlainDateTime::new_unchecked(
+                IsoDateTime::new_unchecked(intermediate_date, start.iso.time),
+                self.calendar.clone(),
+            );
+            // c. Let intermediateNs be ? GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+            let intermediate_ns = self.tz.get_epoch_nanoseconds_for(&intermediate)?;
+            // d. Set timeDuration to TimeDurationFromEpochNanosecondsDifference(ns2, intermediateNs).
+            let norm = NormalizedTimeDuration::from_nanosecond_difference(
+                other.epoch_nanos,
+                intermediate_ns.epoch_nanos,
+            )?;
+            // e. Let timeSign be TimeDurationSign(timeDuration).
+            // f. If sign ≠ -timeSign, then set success to true.
+            if norm.sign() != sign.negate() {
+                success = Some((intermediate_date, norm));
+            }
+            // g. Set dayCorrection to dayCorrection + 1.
+            day_correction += 1;
+        }
+        // 12. Assert: success is true.
+        let (intermediate_date, norm) = success.temporal_unwrap()?;
+        // 13. Let dateLargestUnit be LargerOfTwoTemporalUnits(largestUnit, day).
+        let date_largest_unit = largest_unit.max(TemporalUnit::Day);
+        // 14. Let dateDifference be CalendarDateUntil(calendar, startDateTime.[[ISODate]], intermediateDate, dateLargestUnit).
+        let date_diff = PlainDate::new_unchecked(start.iso.date, self.calendar.clone())
+            .internal_diff_date(
+                &PlainDate::new_unchecked(intermediate_date, self.calendar.clone()),
+                date_largest_unit,
+            )?;
+        // 15. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+        NormalizedDurationRecord::new(*date_diff.date(), norm)
+    }
+
+    /// Returns the difference between this `ZonedDateTime` and `other` rounded with the
+    /// provided options.
+    ///
+    /// Temporal Equivalent: `DifferenceZonedDateTimeWithRounding ( ns1, ns2, calendarRec, timeZoneRec,
+    /// precalculatedPlainDateTime, resolvedOptions, largestUnit, roundingIncrement, smallestUnit, roundingMode )`
+    pub(crate) fn diff_zdt_with_rounding(
+        &self,
+        other: &Instant,
+        options: ResolvedRoundingOptions,
+    ) -> TemporalResult<RelativeRoundResult> {
+        // 1. If TemporalUnitCategory(largestUnit) is time, then
+        if options.largest_unit.is_time_unit() {
+            // a. Return ! DifferenceInstant(ns1, ns2, roundingIncrement, smallestUnit, largestUnit, roundingMode).
+            let norm = NormalizedTimeDuration::from_nanosecond_difference(
+                other.epoch_nanos,
+                self.instant.epoch_nanos,
+            )?;
+            let (round_record, total) = norm.round(FiniteF64::default(), options)?;
+            let (_, time) = TimeDuration::from_normalized(
+                round_record.normalized_time_duration(),
+                options.largest_unit,
+            )?;
+            return Ok((Duration::from(time), total));
+        }
+        // 2. If precalculatedPlainDateTime is undefined, then
+        // a. Let precalculatedPlainDateTime be ? GetPlainDateTimeFor(timeZoneRec, ns1, calendar).
+        let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+        // 3. Let diff be ? DifferenceZonedDateTime(ns1, ns2, timeZoneRec, calendarRec, largestUnit,
+        // resolvedOptions, precalculatedPlainDateTime).
+        let diff = self.diff_instant(other, options.largest_unit)?;
+        // 4. If smallestUnit is "nanosecond" and roundingIncrement = 1, then
+        if options.smallest_unit == TemporalUnit::Nanosecond && options.increment.get() == 1 {
+            // a. Let timeResult be ! BalanceTimeDuration(diff.[[NormalizedTime]], "hour").
+            let (_, time) =
+                TimeDuration::from_normalized(diff.normalized_time_duration(), TemporalUnit::Hour)?;
+            // b. Return the Record { [[DurationRecord]]: CreateDurationRecord(diff.[[Years]],
+            // diff.[[Months]], diff.[[Weeks]], diff.[[Days]], timeResult.[[Hours]], ...), [[Total]]: total }.
+            // NOTE: A total is not computed for a zoned difference.
+            return Ok((Duration::new_unchecked(diff.date(), time), None));
+        }
+        // 5. Return ? RoundRelativeDuration(diff, ns2, precalculatedPlainDateTime, calendarRec,
+        // timeZoneRec, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+        diff.round_relative_duration(other.epoch_nanos, &dt, Some(self.tz.clone()), options)
+    }
 }
 
 // ==== Public API ====
@@ -69,6 +268,121 @@ impl ZonedDateTime {
         &self.tz
     }
 
+    /// Returns a new `ZonedDateTime` for the same instant projected into the provided `TimeZone`.
+    #[inline]
+    #[must_use]
+    pub fn with_time_zone(&self, tz: TimeZone) -> Self {
+        Self::new_unchecked(self.instant.clone(), self.calendar.clone(), tz)
+    }
+
+    /// Returns a new `ZonedDateTime` for the same instant interpreted with the provided `Calendar`.
+    #[inline]
+    #[must_use]
+    pub fn with_calendar(&self, calendar: Calendar) -> Self {
+        Self {
+            calendar,
+            ..self.clone()
+        }
+    }
+
+    /// Rounds this `ZonedDateTime` according to the provided options.
+    ///
+    /// A `smallest_unit` of `day` or a time unit is required. Rounding to a day uses the exact
+    /// length of the local day, from its start until the start of the next day, so a 23 or 25
+    /// hour day is rounded at its actual midpoint rather than at 12:00. Any other unit rounds the
+    /// wall-clock time, which is then interpreted with the current offset.
+    pub fn round(&self, options: RoundingOptions) -> TemporalResult<Self> {
+        // 4-9. Resolve smallestUnit, roundingIncrement and roundingMode.
+        let Some(smallest_unit) = options.smallest_unit else {
+            return Err(TemporalError::range()
+                .with_message("smallestUnit is required for a ZonedDateTime.round operation."));
+        };
+        let increment = options.increment.unwrap_or_default();
+        let rounding_mode = options.rounding_mode.unwrap_or_default();
+        // 10. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit) or 1 for "day".
+        // 11. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        let Some((maximum, inclusive)) = smallest_unit.to_maximum_date_time_rounding_increment()
+        else {
+            return Err(
+                TemporalError::range().with_message("smallestUnit must be a day or a time unit.")
+            );
+        };
+        increment.validate(maximum.into(), inclusive)?;
+
+        // 12. If smallestUnit is "nanosecond" and roundingIncrement = 1, then
+        if smallest_unit == TemporalUnit::Nanosecond && increment == RoundingIncrement::ONE {
+            // a. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, calendar).
+            return Ok(self.clone());
+        }
+
+        // 13. Let thisNs be zonedDateTime.[[EpochNanoseconds]].
+        // 14. Let isoDateTime be GetISODateTimeFor(timeZone, thisNs).
+        let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+
+        // 15. If smallestUnit is "day", then
+        let epoch_nanos = if smallest_unit == TemporalUnit::Day {
+            // a. Let dateStart be isoDateTime.[[ISODate]].
+            // b. Let dateEnd be BalanceISODate(dateStart.[[Year]], dateStart.[[Month]], dateStart.[[Day]] + 1).
+            let date_start = dt.iso.date;
+            let date_end = IsoDate::from_epoch_days(date_start.to_epoch_days() + 1);
+            // c. Let startNs be ? GetStartOfDay(timeZone, dateStart).
+            // d. Assert: thisNs ≥ startNs.
+            // e. Let endNs be ? GetStartOfDay(timeZone, dateEnd).
+            // f. Assert: thisNs < endNs.
+            let start_ns = self.tz.get_start_of_day(date_start)?.epoch_nanos;
+            let end_ns = self.tz.get_start_of_day(date_end)?.epoch_nanos;
+            // g. Let dayLengthNs be ℝ(endNs - startNs).
+            // h. Let dayProgressNs be TimeDurationFromEpochNanosecondsDifference(thisNs, startNs).
+            let day_length = NonZeroU128::new((end_ns - start_ns) as u128).temporal_unwrap()?;
+            let day_progress = self.instant.epoch_nanos - start_ns;
+            // i. Let roundedDayNs be ! RoundTimeDurationToIncrement(dayProgressNs, dayLengthNs, roundingMode).
+            let rounded_day_ns =
+                IncrementRounder::<i128>::from_positive_parts(day_progress, day_length)?
+                    .round_as_positive(rounding_mode);
+            // j. Let epochNanoseconds be AddTimeDurationToEpochNanoseconds(roundedDayNs, startNs).
+            // NOTE: The rounded progress is at most the length of a day.
+            start_ns + rounded_day_ns as i128
+        // 16. Else,
+        } else {
+            // a. Let roundResult be RoundISODateTime(isoDateTime, roundingIncrement, smallestUnit, roundingMode).
+            let round_result = dt.iso.round(ResolvedRoundingOptions {
+                largest_unit: TemporalUnit::Auto,
+                smallest_unit,
+                increment,
+                rounding_mode,
+            })?;
+            // b. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, thisNs).
+            // c. Let epochNanoseconds be ? InterpretISODateTimeOffset(roundResult.[[ISODate]], roundResult.[[Time]],
+            // option, offsetNanoseconds, timeZone, compatible, prefer, match-exactly).
+            // TODO: Prefer the current offset among the possible instants once IANA time zones are supported.
+            let rounded = PlainDateTime::new_unchecked(round_result, self.calendar.clone());
+            self.tz
+                .get_possible_instant_for(&rounded)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    TemporalError::range()
+                        .with_message("Wall-clock times in a time zone gap are not yet supported.")
+                })?
+                .epoch_nanos
+        };
+
+        // 17. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Self::new(epoch_nanos, self.calendar.clone(), self.tz.clone())
+    }
+
+    /// Returns the offset from UTC in nanoseconds in effect for this `ZonedDateTime`.
+    pub fn offset_nanoseconds(&self) -> TemporalResult<i64> {
+        self.tz.get_offset_nanos_for()?.to_i64().temporal_unwrap()
+    }
+
+    /// Returns the offset from UTC in effect for this `ZonedDateTime` as a string, e.g. `-04:00`.
+    ///
+    /// Seconds and sub-seconds are only included when present.
+    pub fn offset(&self) -> TemporalResult<String> {
+        Ok(format_offset_nanoseconds(self.offset_nanoseconds()?))
+    }
+
     /// Returns the `epochSeconds` value of this `ZonedDateTime`.
     #[must_use]
     pub fn epoch_seconds(&self) -> f64 {
@@ -118,6 +432,18 @@ impl ZonedDateTime {
         self.calendar.day(&CalendarDateLike::DateTime(&dt))
     }
 
+    /// Returns the `era` value for this `ZonedDateTime`.
+    pub fn era(&self) -> TemporalResult<Option<TinyAsciiStr<16>>> {
+        let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+        self.calendar.era(&CalendarDateLike::DateTime(&dt))
+    }
+
+    /// Returns the `eraYear` value for this `ZonedDateTime`.
+    pub fn era_year(&self) -> TemporalResult<Option<i32>> {
+        let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
+        self.calendar.era_year(&CalendarDateLike::DateTime(&dt))
+    }
+
     /// Returns the `hour` value for this `ZonedDateTime`.
     pub fn hour(&self) -> TemporalResult<u8> {
         let dt = self.tz.get_datetime_for(&self.instant, &self.calendar)?;
@@ -155,14 +481,87 @@ impl ZonedDateTime {
     }
 }
 
+impl FromStr for ZonedDateTime {
+    type Err = TemporalError;
+
+    /// Parses a `ZonedDateTime` string, which must contain a time zone annotation, e.g.
+    /// `2024-01-01T00:00-05:00[-05:00]`.
+    ///
+    /// A UTC offset in the string must agree with the offset of the time zone.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (record, annotations) = parse_zoned_date_time(s)?;
+        let annotation = annotations.time_zone.temporal_unwrap()?;
+
+        let tz = match annotation.tz {
+            TimeZoneRecord::Name(name) => TimeZone {
+                iana: Some(name.into()),
+                offset: None,
+            },
+            TimeZoneRecord::Offset(offset) => TimeZone {
+                iana: None,
+                offset: Some(
+                    i16::from(offset.sign as i8)
+                        * (i16::from(offset.hour) * 60 + i16::from(offset.minute)),
+                ),
+            },
+        };
+        let calendar = Calendar::from_str(
+            annotations
+                .calendar
+                .map_or("iso8601", |annotation| annotation.value),
+        )?;
+
+        let date_time = PlainDateTime::from_parse_record(&record)?;
+        let instant = tz
+            .get_possible_instant_for(&date_time)?
+            .into_iter()
+            .next()
+            .temporal_unwrap()?;
+
+        if let Some(offset) = record.offset {
+            if tz.get_offset_nanos_for()? != utc_offset_nanoseconds(&offset).into() {
+                return Err(TemporalError::range()
+                    .with_message("ZonedDateTime offset does not match its time zone."));
+            }
+        }
+
+        Self::new(instant.epoch_nanos, calendar, tz)
+    }
+}
+
+// ==== Utility Functions ====
+
+/// Formats an offset in nanoseconds as `±HH:MM`, with `:SS` and a fraction only when non-zero.
+///
+/// Equivalent to `FormatUTCOffsetNanoseconds`.
+fn format_offset_nanoseconds(offset: i64) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let hours = offset / 3_600_000_000_000;
+    let minutes = offset / 60_000_000_000 % 60;
+    let seconds = offset / 1_000_000_000 % 60;
+    let subseconds = (offset % 1_000_000_000) as u32;
+
+    if seconds == 0 && subseconds == 0 {
+        return format!("{sign}{hours:02}:{minutes:02}");
+    }
+    let fraction = format_fractional_seconds(subseconds, Precision::Auto);
+    format!("{sign}{hours:02}:{minutes:02}:{seconds:02}{fraction}")
+}
+
 #[cfg(test)]
 mod tests {
 
-    use std::str::FromStr;
+    use core::{cmp::Ordering, str::FromStr};
+
+    use tinystr::tinystr;
 
-    use crate::components::{calendar::Calendar, tz::TimeZone};
+    use crate::{
+        components::{calendar::Calendar, tz::TimeZone},
+        options::{RoundingIncrement, RoundingOptions, TemporalRoundingMode, TemporalUnit},
+    };
 
-    use super::ZonedDateTime;
+    use super::{format_offset_nanoseconds, ZonedDateTime};
 
     #[test]
     fn basic_zdt_test() {
@@ -202,4 +601,173 @@ mod tests {
         assert_eq!(zdt_minus_five.minute().unwrap(), 49);
         assert_eq!(zdt_minus_five.second().unwrap(), 12);
     }
+
+    #[test]
+    fn with_time_zone_and_calendar() {
+        let nov_30_2023_utc = 1_701_308_952_000_000_000i128;
+        let utc = ZonedDateTime::new(
+            nov_30_2023_utc,
+            Calendar::from_str("iso8601").unwrap(),
+            TimeZone {
+                iana: None,
+                offset: Some(0),
+            },
+        )
+        .unwrap();
+
+        let minus_five = utc.with_time_zone(TimeZone {
+            iana: None,
+            offset: Some(-300),
+        });
+        assert_eq!(minus_five.cmp(&utc), Ordering::Equal);
+        assert_eq!(minus_five.epoch_nanoseconds(), utc.epoch_nanoseconds());
+        assert_eq!(minus_five.day().unwrap(), 29);
+        assert_eq!(minus_five.hour().unwrap(), 20);
+
+        assert_eq!(utc.era().unwrap(), None);
+        let gregory = utc.with_calendar(Calendar::from_str("gregory").unwrap());
+        assert_eq!(gregory.cmp(&utc), Ordering::Equal);
+        assert_eq!(gregory.era().unwrap(), Some(tinystr!(16, "ce")));
+        assert_eq!(gregory.era_year().unwrap(), Some(2023));
+        assert_eq!((gregory.month().unwrap(), gregory.day().unwrap()), (11, 30));
+    }
+
+    #[test]
+    fn from_str_uses_annotations() {
+        let zdt =
+            ZonedDateTime::from_str("2024-03-10T02:30:00-05:00[-05:00][u-ca=gregory]").unwrap();
+        assert_eq!(zdt.instant.epoch_nanos, 1_710_055_800_000_000_000);
+        assert_eq!(zdt.calendar().identifier(), "gregory");
+        assert_eq!(zdt.offset().unwrap(), "-05:00");
+
+        // The time zone annotation is required and must agree with the offset.
+        assert!(ZonedDateTime::from_str("2024-03-10T02:30:00-05:00").is_err());
+        assert!(ZonedDateTime::from_str("2024-03-10T02:30:00+01:00[-05:00]").is_err());
+        // Unknown annotations are only rejected when they are critical.
+        assert!(ZonedDateTime::from_str("2024-03-10T02:30:00[-05:00][x-foo=bar]").is_ok());
+        assert!(ZonedDateTime::from_str("2024-03-10T02:30:00[-05:00][!x-foo=bar]").is_err());
+    }
+
+    #[test]
+    fn offset_accessors() {
+        let zdt = ZonedDateTime::new(
+            1_701_308_952_000_000_000,
+            Calendar::default(),
+            TimeZone {
+                iana: None,
+                offset: Some(-240),
+            },
+        )
+        .unwrap();
+        assert_eq!(zdt.offset_nanoseconds().unwrap(), -14_400_000_000_000);
+        assert_eq!(zdt.offset().unwrap(), "-04:00");
+
+        let zdt = zdt.with_time_zone(TimeZone {
+            iana: None,
+            offset: Some(330),
+        });
+        assert_eq!(zdt.offset().unwrap(), "+05:30");
+
+        // Construction does not resolve the offset, so a named time zone can still be used.
+        let named = TimeZone {
+            iana: Some("America/New_York".into()),
+            offset: None,
+        };
+        assert_eq!(zdt.with_time_zone(named.clone()).tz(), &named);
+
+        assert_eq!(format_offset_nanoseconds(0), "+00:00");
+        assert_eq!(format_offset_nanoseconds(-3_723_000_000_000), "-01:02:03");
+        assert_eq!(format_offset_nanoseconds(3_723_500_000_000), "+01:02:03.5");
+    }
+
+    // TODO: Add a 23-hour spring-forward day, which rounds at 11:30, once IANA time zones are
+    // supported.
+    #[test]
+    fn round_to_day_and_time_units() {
+        let tz = TimeZone {
+            iana: None,
+            offset: Some(-300),
+        };
+        // 2024-03-10T12:00:00-05:00
+        let noon = ZonedDateTime::new(1_710_090_000_000_000_000, Calendar::default(), tz).unwrap();
+        let options = |unit, increment, rounding_mode| RoundingOptions {
+            largest_unit: None,
+            smallest_unit: Some(unit),
+            increment: increment.map(|increment| RoundingIncrement::try_new(increment).unwrap()),
+            rounding_mode,
+        };
+        let assert_local = |zdt: ZonedDateTime, expected: (u8, u8, u8, u8)| {
+            assert_eq!(
+                (
+                    zdt.day().unwrap(),
+                    zdt.hour().unwrap(),
+                    zdt.minute().unwrap(),
+                    zdt.second().unwrap()
+                ),
+                expected
+            );
+            assert_eq!(zdt.offset().unwrap(), "-05:00");
+        };
+
+        // The midpoint of a 24-hour day rounds half expand to the start of the next day.
+        let result = noon.round(options(TemporalUnit::Day, None, None)).unwrap();
+        assert_local(result, (11, 0, 0, 0));
+        let result = noon
+            .round(options(
+                TemporalUnit::Day,
+                None,
+                Some(TemporalRoundingMode::HalfTrunc),
+            ))
+            .unwrap();
+        assert_local(result, (10, 0, 0, 0));
+        let before_noon = ZonedDateTime::new(
+            1_710_089_999_999_999_999,
+            Calendar::default(),
+            noon.tz().clone(),
+        )
+        .unwrap();
+        let result = before_noon
+            .round(options(TemporalUnit::Day, None, None))
+            .unwrap();
+        assert_local(result, (10, 0, 0, 0));
+
+        // 2024-03-10T17:29:30.5-05:00
+        let zdt = ZonedDateTime::new(
+            1_710_109_770_500_000_000,
+            Calendar::default(),
+            noon.tz().clone(),
+        )
+        .unwrap();
+        let result = zdt.round(options(TemporalUnit::Hour, None, None)).unwrap();
+        assert_local(result, (10, 17, 0, 0));
+        let result = zdt
+            .round(options(TemporalUnit::Minute, Some(15), None))
+            .unwrap();
+        assert_local(result, (10, 17, 30, 0));
+        let result = zdt
+            .round(options(TemporalUnit::Second, None, None))
+            .unwrap();
+        assert_local(result, (10, 17, 29, 31));
+        assert_eq!(result.epoch_nanoseconds(), 1_710_109_771_000_000_000.0);
+
+        let result = zdt
+            .round(options(TemporalUnit::Nanosecond, None, None))
+            .unwrap();
+        assert_eq!(result, zdt);
+
+        // A day only allows an increment of 1, and calendar units are not valid.
+        assert!(zdt
+            .round(options(TemporalUnit::Day, Some(2), None))
+            .is_err());
+        assert!(zdt
+            .round(options(TemporalUnit::Hour, Some(24), None))
+            .is_err());
+        assert!(zdt.round(options(TemporalUnit::Week, None, None)).is_err());
+        assert!(zdt
+            .round(RoundingOptions {
+                smallest_unit: None,
+                ..options(TemporalUnit::Hour, None, None)
+            })
+            .is_err());
+    }
 }
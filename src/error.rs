@@ -5,7 +5,9 @@ use core::fmt;
 use icu_calendar::CalendarError;
 
 /// `TemporalError`'s error type.
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+///
+/// The kind maps onto the ECMAScript exception type that a host should throw.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorKind {
     /// Error.
     #[default]
@@ -17,11 +19,19 @@ pub enum ErrorKind {
     /// SyntaxError
     Syntax,
     /// Assert
+    ///
+    /// An internal invariant of the implementation was violated.
     Assert,
 }
 
-impl fmt::Display for ErrorKind {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ErrorKind {
+    /// Returns the stable string code of this kind, e.g. `"RangeError"`.
+    ///
+    /// The codes are the names of the ECMAScript error types, except for `Assert`, which is
+    /// `"ImplementationError"` as it does not correspond to an ECMAScript error.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
         match self {
             Self::Generic => "Error",
             Self::Type => "TypeError",
@@ -29,7 +39,12 @@ impl fmt::Display for ErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Assert => "ImplementationError",
         }
-        .fmt(f)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
     }
 }
 
@@ -86,6 +101,11 @@ impl TemporalError {
         Self::syntax().with_message("Abrupt end to parsing target.")
     }
 
+    /// Create a range error for a leap second, which Temporal does not represent.
+    pub(crate) fn leap_second() -> Self {
+        Self::range().with_message("Leap seconds are not supported; second must be 0–59.")
+    }
+
     /// Add a message to the error.
     #[must_use]
     pub fn with_message<S>(mut self, msg: S) -> Self
@@ -102,11 +122,27 @@ impl TemporalError {
         self.kind
     }
 
+    /// Returns the stable string code of this error, e.g. `"RangeError"`, for hosts and
+    /// serialized error payloads.
+    ///
+    /// See `ErrorKind::as_str`.
+    #[inline]
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
     /// Returns the error message.
     #[must_use]
     pub fn message(&self) -> &str {
         &self.msg
     }
+
+    /// Consumes the error, returning its message.
+    #[must_use]
+    pub fn into_message(self) -> Box<str> {
+        self.msg
+    }
 }
 
 impl fmt::Display for TemporalError {
@@ -122,8 +158,44 @@ impl fmt::Display for TemporalError {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for TemporalError {}
+
 impl From<CalendarError> for TemporalError {
     fn from(value: CalendarError) -> Self {
         TemporalError::general(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorKind, TemporalError};
+
+    #[test]
+    fn error_kind_inspection() {
+        let err = TemporalError::range().with_message("Value out of range.");
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(err.message(), "Value out of range.");
+        assert_eq!(err.to_string(), "RangeError: Value out of range.");
+        assert_eq!(&*err.into_message(), "Value out of range.");
+
+        assert_eq!(TemporalError::r#type().kind(), ErrorKind::Type);
+        assert_eq!(TemporalError::abrupt_end().kind(), ErrorKind::Syntax);
+        assert_eq!(TemporalError::general("Error.").kind(), ErrorKind::Generic);
+        assert_eq!(TemporalError::assert().kind(), ErrorKind::Assert);
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(TemporalError::range().code(), "RangeError");
+        assert_eq!(TemporalError::r#type().code(), "TypeError");
+        assert_eq!(TemporalError::syntax().code(), "SyntaxError");
+        assert_eq!(TemporalError::general("Error.").code(), "Error");
+        assert_eq!(TemporalError::assert().code(), "ImplementationError");
+
+        // The code is the prefix of the displayed error.
+        let err = TemporalError::range().with_message("Value out of range.");
+        assert!(err.to_string().starts_with(err.code()));
+        assert_eq!(err.kind().to_string(), err.code());
+    }
+}
@@ -11,8 +11,18 @@
 //! `[[ISOmicrosecond]]`, and `[[ISOnanosecond]]` internal slots.
 //!
 //! An `IsoDateTime` has the internal slots of both an `IsoDate` and `IsoTime`.
+//!
+//! These records are exposed for advanced users building their own date/time types on top of
+//! Temporal's arithmetic. Prefer the components like `PlainTime` for general use.
+//!
+//! ## `IsoTime` invariants
+//!
+//! Every public way of creating or operating on an `IsoTime` returns a valid time, i.e. an hour
+//! in `0..=23`, a minute and second in `0..=59`, and sub-second fields in `0..=999`. Operations
+//! that can carry past midnight, like `round`, return the carry as a separate day count.
+//! `IsoTime::diff` returns an unbalanced `TimeDuration` whose fields may be negative.
 
-use std::num::NonZeroU128;
+use core::num::NonZeroU128;
 
 use crate::{
     components::{
@@ -35,7 +45,7 @@ use num_traits::{cast::FromPrimitive, AsPrimitive, ToPrimitive};
 
 /// `IsoDateTime` is the record of the `IsoDate` and `IsoTime` internal slots.
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsoDateTime {
     pub(crate) date: IsoDate,
     pub(crate) time: IsoTime,
@@ -57,74 +67,38 @@ impl IsoDateTime {
         Ok(Self::new_unchecked(date, time))
     }
 
-    // NOTE: The below assumes that nanos is from an `Instant` and thus in a valid range. -> Needs validation.
-    /// Creates an `IsoDateTime` from a `BigInt` of epochNanoseconds.
-    #[allow(clippy::neg_cmp_op_on_partial_ord)]
-    pub(crate) fn from_epoch_nanos(nanos: &i128, offset: f64) -> TemporalResult<Self> {
-        // Skip the assert as nanos should be validated by Instant.
-        // TODO: Determine whether value needs to be validated as integral.
-        // Get the component ISO parts
-        let mathematical_nanos = nanos.to_f64().ok_or_else(|| {
+    /// Balances a carry of days, e.g. from time arithmetic overflowing a day, into the date of
+    /// an `IsoDateTime`.
+    ///
+    /// `date` and `time` are expected to be valid, e.g. the time and day overflow returned by
+    /// `IsoTime::balance`. The carry may be negative, and is added across month and year
+    /// boundaries as well as leap days.
+    ///
+    /// # Errors:
+    ///   - Will error if the result is not within the ISO date time limits
+    pub fn balance(date: IsoDate, time: IsoTime, day_carry: i64) -> TemporalResult<Self> {
+        Self::new(date.add_days(day_carry)?, time)
+    }
+
+    // NOTE: The below assumes that nanos is from an `Instant` and thus in a valid range.
+    /// Creates an `IsoDateTime` from an `i128` of epochNanoseconds and an offset in nanoseconds.
+    ///
+    /// The decomposition is done in integer arithmetic, so the result is exact for any epoch.
+    pub(crate) fn from_epoch_nanos(nanos: &i128, offset: i128) -> TemporalResult<Self> {
+        let ns_per_day = i128::from(NS_PER_DAY);
+        let local_nanos = nanos.checked_add(offset).temporal_unwrap()?;
+        // NOTE: Flooring division keeps epochs before 1970 on the preceding day.
+        let epoch_days = i32::try_from(local_nanos.div_euclid(ns_per_day)).map_err(|_| {
             TemporalError::range().with_message("nanos was not within a valid range.")
         })?;
-
-        // 2. Let remainderNs be epochNanoseconds modulo 10^6.
-        let remainder_nanos = mathematical_nanos % 1_000_000f64;
-
-        // 3. Let epochMilliseconds be 𝔽((epochNanoseconds - remainderNs) / 10^6).
-        let epoch_millis = ((mathematical_nanos - remainder_nanos) / 1_000_000f64).floor();
-
-        let year = utils::epoch_time_to_epoch_year(epoch_millis);
-        let month = utils::epoch_time_to_month_in_year(epoch_millis) + 1;
-        let day = utils::epoch_time_to_date(epoch_millis);
-
-        // 7. Let hour be ℝ(! HourFromTime(epochMilliseconds)).
-        let hour = (epoch_millis / 3_600_000f64).floor() % 24f64;
-        // 8. Let minute be ℝ(! MinFromTime(epochMilliseconds)).
-        let minute = (epoch_millis / 60_000f64).floor() % 60f64;
-        // 9. Let second be ℝ(! SecFromTime(epochMilliseconds)).
-        let second = (epoch_millis / 1000f64).floor() % 60f64;
-        // 10. Let millisecond be ℝ(! msFromTime(epochMilliseconds)).
-        let millis = (epoch_millis % 1000f64).floor() % 1000f64;
-
-        // 11. Let microsecond be floor(remainderNs / 1000).
-        let micros = (remainder_nanos / 1000f64).floor();
-        // 12. Assert: microsecond < 1000.
-        temporal_assert!(micros < 1000f64);
-        // 13. Let nanosecond be remainderNs modulo 1000.
-        let nanos = (remainder_nanos % 1000f64).floor();
-
-        Ok(Self::balance(
-            year,
-            i32::from(month),
-            i32::from(day),
-            hour,
-            minute,
-            second,
-            millis,
-            micros,
-            nanos + offset,
+        // NOTE: The remainder is within 0..NS_PER_DAY, so it fits a `u64`.
+        let time = IsoTime::from_nanoseconds_unchecked(local_nanos.rem_euclid(ns_per_day) as u64);
+        Ok(Self::new_unchecked(
+            IsoDate::from_epoch_days(epoch_days),
+            time,
         ))
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn balance(
-        year: i32,
-        month: i32,
-        day: i32,
-        hour: f64,
-        minute: f64,
-        second: f64,
-        millisecond: f64,
-        microsecond: f64,
-        nanosecond: f64,
-    ) -> Self {
-        let (overflow_day, time) =
-            IsoTime::balance(hour, minute, second, millisecond, microsecond, nanosecond);
-        let date = IsoDate::balance(year, month, day + overflow_day);
-        Self::new_unchecked(date, time)
-    }
-
     /// Returns whether the `IsoDateTime` is within valid limits.
     pub(crate) fn is_within_limits(&self) -> bool {
         iso_dt_within_valid_limits(self.date, &self.time)
@@ -147,7 +121,17 @@ impl IsoDateTime {
         // 1. Assert: IsValidISODate(year, month, day) is true.
         // 2. Assert: ISODateTimeWithinLimits(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond) is true.
         // 3. Let timeResult be AddTime(hour, minute, second, millisecond, microsecond, nanosecond, norm).
-        let t_result = self.time.add(norm);
+        let t_result = self.time.add(norm)?;
+
+        // NOTE: Days are the same length in every calendar, so a duration without years, months,
+        // or weeks only needs the day carry balanced into the date.
+        if date_duration.years.is_zero()
+            && date_duration.months.is_zero()
+            && date_duration.weeks.is_zero()
+        {
+            let days = date_duration.days.as_::<i64>() + i64::from(t_result.0);
+            return Self::balance(self.date, t_result.1, days);
+        }
 
         // 4. Let datePart be ! CreateTemporalDate(year, month, day, calendarRec.[[Receiver]]).
         let date = PlainDate::new_unchecked(self.date, calendar);
@@ -175,12 +159,7 @@ impl IsoDateTime {
 
     pub(crate) fn round(&self, resolved_options: ResolvedRoundingOptions) -> TemporalResult<Self> {
         let (rounded_days, rounded_time) = self.time.round(resolved_options)?;
-        let balance_result = IsoDate::balance(
-            self.date.year,
-            self.date.month.into(),
-            i32::from(self.date.day) + rounded_days,
-        );
-        Self::new(balance_result, rounded_time)
+        Self::balance(self.date, rounded_time, i64::from(rounded_days))
     }
 
     // TODO: Determine whether to provide an options object...seems duplicative.
@@ -277,7 +256,7 @@ pub trait IsoDateSlots {
 /// These fields are used for the `Temporal.PlainDate` object, the
 /// `Temporal.YearMonth` object, and the `Temporal.MonthDay` object.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsoDate {
     pub(crate) year: i32,
     pub(crate) month: u8,
@@ -346,12 +325,72 @@ impl IsoDate {
         iso_date_to_epoch_days(self.year, (self.month - 1).into(), self.day.into())
     }
 
+    /// Returns the `IsoDate` that is `days` after this date.
+    ///
+    /// The days are added through the epoch days, so they correctly cross month and year
+    /// boundaries as well as leap days. The result is not validated against the ISO date time
+    /// limits, but a result that can never be valid is a `RangeError`.
+    pub(crate) fn add_days(self, days: i64) -> TemporalResult<Self> {
+        let epoch_days = i64::from(self.to_epoch_days()).saturating_add(days);
+        // NOTE: Any valid `IsoDateTime` is within 10^8 + 1 epoch days.
+        if epoch_days.abs() > 100_000_001 {
+            return Err(TemporalError::range().with_message("IsoDate not within a valid range."));
+        }
+        Ok(Self::from_epoch_days(epoch_days as i32))
+    }
+
+    /// Creates an `IsoDate` from the days since the Unix epoch without validating the result
+    /// against the ISO date time limits.
+    pub(crate) fn from_epoch_days(epoch_days: i32) -> Self {
+        // NOTE: This is the `civil_from_days` algorithm, using eras of 400 years starting on
+        // March 1st, so the leap day is the last day of the era's year.
+        let shifted_days = i64::from(epoch_days) + 719_468;
+        let era = shifted_days.div_euclid(146_097);
+        let day_of_era = shifted_days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = era * 400 + year_of_era + i64::from(month <= 2);
+        // NOTE: An `i32` of epoch days is always within an `i32` range of years.
+        Self::new_unchecked(year as i32, month as u8, day as u8)
+    }
+
+    /// Returns the ISO 8601 week number and week-numbering year of this `IsoDate`.
+    pub(crate) fn iso_week_of_year(self) -> (u8, i32) {
+        self.week_of_year_with(1, 4)
+    }
+
+    /// Returns the week number and week-numbering year of this `IsoDate` for weeks starting on
+    /// `first_day` (1 is Monday, 7 is Sunday), where the first week of a year is the first week
+    /// with at least `min_days` (1 to 7) days in that year.
+    pub(crate) fn week_of_year_with(self, first_day: u8, min_days: u8) -> (u8, i32) {
+        let epoch_days = self.to_epoch_days();
+        // NOTE: 1970-01-01 is a Thursday, so Monday is 1 and Sunday is 7.
+        let day_of_week = (epoch_days + 3).rem_euclid(7) + 1;
+        let week_start = epoch_days - (day_of_week - i32::from(first_day)).rem_euclid(7);
+        // A week belongs to the year that contains its last `min_days` days, e.g. the Thursday
+        // of an ISO week.
+        let anchor = week_start + 7 - i32::from(min_days);
+        let week_year = Self::from_epoch_days(anchor).year;
+        let year_start = iso_date_to_epoch_days(week_year, 0, 1);
+        ((((anchor - year_start) / 7) + 1) as u8, week_year)
+    }
+
     /// Returns if the current `IsoDate` is valid.
     pub(crate) fn is_valid(self) -> bool {
         is_valid_date(self.year, self.month.into(), self.day.into())
     }
 
     /// Returns the resulting `IsoDate` from adding a provided `Duration` to this `IsoDate`
+    ///
+    /// Equivalent to `AddISODate`.
     pub(crate) fn add_date_duration(
         self,
         duration: &DateDuration,
@@ -374,17 +413,16 @@ impl IsoDate {
         )?;
 
         // 5. Set days to days + 7 × weeks.
-        let additional_days =
-            duration.days.as_date_value()? + (duration.weeks.as_date_value()? * 7);
+        let days = duration.days.as_::<i64>() + duration.weeks.as_::<i64>() * 7;
         // 6. Let d be intermediate.[[Day]] + days.
-        let d = i32::from(intermediate.day) + additional_days;
-
         // 7. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], d).
-        Ok(Self::balance(
-            intermediate.year,
-            intermediate.month.into(),
-            d,
-        ))
+        // NOTE: The days are balanced through the epoch days, which can not overflow for any
+        // valid duration.
+        let epoch_days =
+            i32::try_from(i64::from(intermediate.to_epoch_days()) + days).map_err(|_| {
+                TemporalError::range().with_message("Resulting date is not within a valid range.")
+            })?;
+        Ok(Self::from_epoch_days(epoch_days))
     }
 
     pub(crate) fn diff_iso_date(
@@ -497,7 +535,7 @@ impl IsoDate {
 /// An `IsoTime` record that contains `Temporal`'s
 /// time slots.
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsoTime {
     pub(crate) hour: u8,         // 0..=23
     pub(crate) minute: u8,       // 0..=59
@@ -508,6 +546,48 @@ pub struct IsoTime {
 }
 
 impl IsoTime {
+    /// Returns the hour value, from 0 to 23.
+    #[inline]
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute value, from 0 to 59.
+    #[inline]
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second value, from 0 to 59.
+    #[inline]
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the millisecond value, from 0 to 999.
+    #[inline]
+    #[must_use]
+    pub const fn millisecond(&self) -> u16 {
+        self.millisecond
+    }
+
+    /// Returns the microsecond value, from 0 to 999.
+    #[inline]
+    #[must_use]
+    pub const fn microsecond(&self) -> u16 {
+        self.microsecond
+    }
+
+    /// Returns the nanosecond value, from 0 to 999.
+    #[inline]
+    #[must_use]
+    pub const fn nanosecond(&self) -> u16 {
+        self.nanosecond
+    }
+
     /// Creates a new `IsoTime` without any validation.
     pub(crate) fn new_unchecked(
         hour: u8,
@@ -528,6 +608,11 @@ impl IsoTime {
     }
 
     /// Creates a new regulated `IsoTime`.
+    ///
+    /// With `ArithmeticOverflow::Constrain`, each field is clamped to its valid range, so a
+    /// negative value becomes `0` rather than wrapping or borrowing from the next larger unit.
+    /// With `ArithmeticOverflow::Reject`, any field outside of its valid range, including a
+    /// negative value, is a `RangeError`.
     pub fn new(
         hour: i32,
         minute: i32,
@@ -548,9 +633,11 @@ impl IsoTime {
                 Ok(Self::new_unchecked(h, min, sec, milli, micro, nano))
             }
             ArithmeticOverflow::Reject => {
-                if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
-                    return Err(TemporalError::range().with_message("IsoTime is not valid"));
-                };
+                // NOTE: Temporal does not represent leap seconds, so `60` gets a dedicated error.
+                if second == 60 {
+                    return Err(TemporalError::leap_second());
+                }
+                validate_time_fields(hour, minute, second, millisecond, microsecond, nanosecond)?;
                 Ok(Self::new_unchecked(
                     hour as u8,
                     minute as u8,
@@ -563,13 +650,11 @@ impl IsoTime {
         }
     }
 
-    /// Creates a new `Time` with the fields provided from a `PartialTime`.
+    /// Creates a new `IsoTime` with the fields provided from a `PartialTime`.
+    ///
+    /// Unset fields are taken from this `IsoTime`, and the result is regulated with `overflow`.
     #[inline]
-    pub(crate) fn with(
-        &self,
-        partial: PartialTime,
-        overflow: ArithmeticOverflow,
-    ) -> TemporalResult<Self> {
+    pub fn with(&self, partial: PartialTime, overflow: ArithmeticOverflow) -> TemporalResult<Self> {
         let hour = partial.hour.unwrap_or(self.hour.into());
         let minute = partial.minute.unwrap_or(self.minute.into());
         let second = partial.second.unwrap_or(self.second.into());
@@ -621,59 +706,88 @@ impl IsoTime {
         )
     }
 
-    // NOTE(nekevss): f64 is needed here as values could exceed i32 when input.
+    // NOTE: i128 is needed here as values could exceed i32 when input.
     /// Balances and creates a new `IsoTime` with `day` overflow from the provided values.
-    pub(crate) fn balance(
-        hour: f64,
-        minute: f64,
-        second: f64,
-        millisecond: f64,
-        microsecond: f64,
-        nanosecond: f64,
-    ) -> (i32, Self) {
-        // 1. Set microsecond to microsecond + floor(nanosecond / 1000).
-        // 2. Set nanosecond to nanosecond modulo 1000.
-        let (quotient, nanosecond) = div_mod(nanosecond, 1000f64);
-        let microsecond = microsecond + quotient;
-
-        // 3. Set millisecond to millisecond + floor(microsecond / 1000).
-        // 4. Set microsecond to microsecond modulo 1000.
-        let (quotient, microsecond) = div_mod(microsecond, 1000f64);
-        let millisecond = millisecond + quotient;
-
-        // 5. Set second to second + floor(millisecond / 1000).
-        // 6. Set millisecond to millisecond modulo 1000.
-        let (quotient, millisecond) = div_mod(millisecond, 1000f64);
-        let second = second + quotient;
-
-        // 7. Set minute to minute + floor(second / 60).
-        // 8. Set second to second modulo 60.
-        let (quotient, second) = div_mod(second, 60f64);
-        let minute = minute + quotient;
-
-        // 9. Set hour to hour + floor(minute / 60).
-        // 10. Set minute to minute modulo 60.
-        let (quotient, minute) = div_mod(minute, 60f64);
-        let hour = hour + quotient;
+    ///
+    /// The fields may be any values, including negative values and values beyond their usual
+    /// range, e.g. 25 hours or -30 minutes. They are balanced as a total of nanoseconds, so the
+    /// result is exact.
+    ///
+    /// The returned `IsoTime` is always valid; negative totals borrow from the day count, e.g.
+    /// -30 minutes balances to `(-1, 23:30)`.
+    ///
+    /// # Errors:
+    ///   - Will error if the total nanoseconds exceed the `i128` range
+    ///   - Will error if the day overflow exceeds the `i32` range
+    pub fn balance(
+        hour: i128,
+        minute: i128,
+        second: i128,
+        millisecond: i128,
+        microsecond: i128,
+        nanosecond: i128,
+    ) -> TemporalResult<(i32, Self)> {
+        // 1-10. Carry each field into the next larger unit.
+        let total_nanoseconds = hour
+            .checked_mul(60)
+            .and_then(|v| v.checked_add(minute))
+            .and_then(|v| v.checked_mul(60))
+            .and_then(|v| v.checked_add(second))
+            .and_then(|v| v.checked_mul(1_000))
+            .and_then(|v| v.checked_add(millisecond))
+            .and_then(|v| v.checked_mul(1_000))
+            .and_then(|v| v.checked_add(microsecond))
+            .and_then(|v| v.checked_mul(1_000))
+            .and_then(|v| v.checked_add(nanosecond))
+            .ok_or_else(time_balance_range_error)?;
 
         // 11. Let days be floor(hour / 24).
         // 12. Set hour to hour modulo 24.
-        let (days, hour) = div_mod(hour, 24f64);
+        let ns_per_day = i128::from(NS_PER_DAY);
+        let days = i32::try_from(total_nanoseconds.div_euclid(ns_per_day))
+            .map_err(|_| time_balance_range_error())?;
+        // NOTE: The remainder is within 0..NS_PER_DAY, so it fits a `u64`.
+        let time =
+            Self::from_nanoseconds_unchecked(total_nanoseconds.rem_euclid(ns_per_day) as u64);
+
+        Ok((days, time))
+    }
 
-        let time = Self::new_unchecked(
+    /// Creates an `IsoTime` from the nanoseconds since midnight.
+    ///
+    /// The caller must ensure that `nanoseconds` is less than the nanoseconds in a day.
+    pub(crate) fn from_nanoseconds_unchecked(nanoseconds: u64) -> Self {
+        debug_assert!(nanoseconds < NS_PER_DAY);
+        let (microseconds, nanosecond) = (nanoseconds / 1_000, nanoseconds % 1_000);
+        let (milliseconds, microsecond) = (microseconds / 1_000, microseconds % 1_000);
+        let (seconds, millisecond) = (milliseconds / 1_000, milliseconds % 1_000);
+        let (minutes, second) = (seconds / 60, seconds % 60);
+        let (hour, minute) = (minutes / 60, minutes % 60);
+        Self::new_unchecked(
             hour as u8,
             minute as u8,
             second as u8,
             millisecond as u16,
             microsecond as u16,
             nanosecond as u16,
-        );
+        )
+    }
 
-        (days as i32, time)
+    /// Returns the nanoseconds since midnight of this `IsoTime`.
+    pub(crate) fn to_nanoseconds_since_midnight(self) -> u64 {
+        let minutes = u64::from(self.hour) * 60 + u64::from(self.minute);
+        let seconds = minutes * 60 + u64::from(self.second);
+        let milliseconds = seconds * 1_000 + u64::from(self.millisecond);
+        let microseconds = milliseconds * 1_000 + u64::from(self.microsecond);
+        microseconds * 1_000 + u64::from(self.nanosecond)
     }
 
     /// Difference this `IsoTime` against another and returning a `TimeDuration`.
-    pub(crate) fn diff(&self, other: &Self) -> TimeDuration {
+    ///
+    /// The result is not balanced, so each field is the difference of the respective fields
+    /// and may be negative, e.g. 10:00 to 09:30 is -1 hour and 30 minutes.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> TimeDuration {
         let h = i32::from(other.hour) - i32::from(self.hour);
         let m = i32::from(other.minute) - i32::from(self.minute);
         let s = i32::from(other.second) - i32::from(self.second);
@@ -695,10 +809,10 @@ impl IsoTime {
     // temporal-polyfill
     // TODO: DayLengthNS can probably be a u64, but keep as is for now and optimize.
     /// Rounds the current `IsoTime` according to the provided settings.
-    pub(crate) fn round(
-        &self,
-        resolved_options: ResolvedRoundingOptions,
-    ) -> TemporalResult<(i32, Self)> {
+    ///
+    /// Returns the rounded time along with a day carry of 0 or 1 when rounding up past midnight.
+    /// Rounding to a `Day` always returns midnight. Date units other than `Day` are rejected.
+    pub fn round(&self, resolved_options: ResolvedRoundingOptions) -> TemporalResult<(i32, Self)> {
         // 1. If unit is "day" or "hour", then
         let quantity = match resolved_options.smallest_unit {
             TemporalUnit::Day | TemporalUnit::Hour => {
@@ -769,72 +883,58 @@ impl IsoTime {
                 .round(resolved_options.rounding_mode)
                 / length.get() as i128;
 
-        let result_f64 = f64::from_i128(result)
-            .ok_or(TemporalError::range().with_message("round result valid range."))?;
-
         match resolved_options.smallest_unit {
             // 9. If unit is "day", then
             // a. Return Time Record { [[Days]]: result, [[Hour]]: 0, [[Minute]]: 0, [[Second]]: 0, [[Millisecond]]: 0, [[Microsecond]]: 0, [[Nanosecond]]: 0  }.
-            TemporalUnit::Day => Ok((result_f64 as i32, Self::default())),
+            TemporalUnit::Day => Ok((result as i32, Self::default())),
             // 10. If unit is "hour", then
             // a. Return BalanceTime(result, 0, 0, 0, 0, 0).
-            TemporalUnit::Hour => Ok(Self::balance(result_f64, 0.0, 0.0, 0.0, 0.0, 0.0)),
+            TemporalUnit::Hour => Self::balance(result, 0, 0, 0, 0, 0),
             // 11. If unit is "minute", then
             // a. Return BalanceTime(hour, result, 0.0, 0.0, 0.0, 0).
-            TemporalUnit::Minute => Ok(Self::balance(
-                self.hour.into(),
-                result_f64,
-                0.0,
-                0.0,
-                0.0,
-                0.0,
-            )),
+            TemporalUnit::Minute => Self::balance(self.hour.into(), result, 0, 0, 0, 0),
             // 12. If unit is "second", then
             // a. Return BalanceTime(hour, minute, result, 0.0, 0.0, 0).
-            TemporalUnit::Second => Ok(Self::balance(
-                self.hour.into(),
-                self.minute.into(),
-                result_f64,
-                0.0,
-                0.0,
-                0.0,
-            )),
+            TemporalUnit::Second => {
+                Self::balance(self.hour.into(), self.minute.into(), result, 0, 0, 0)
+            }
             // 13. If unit is "millisecond", then
             // a. Return BalanceTime(hour, minute, second, result, 0.0, 0).
-            TemporalUnit::Millisecond => Ok(Self::balance(
+            TemporalUnit::Millisecond => Self::balance(
                 self.hour.into(),
                 self.minute.into(),
                 self.second.into(),
-                result_f64,
-                0.0,
-                0.0,
-            )),
+                result,
+                0,
+                0,
+            ),
             // 14. If unit is "microsecond", then
             // a. Return BalanceTime(hour, minute, second, millisecond, result, 0).
-            TemporalUnit::Microsecond => Ok(Self::balance(
+            TemporalUnit::Microsecond => Self::balance(
                 self.hour.into(),
                 self.minute.into(),
                 self.second.into(),
                 self.millisecond.into(),
-                result_f64,
-                0.0,
-            )),
+                result,
+                0,
+            ),
             // 15. Assert: unit is "nanosecond".
             // 16. Return BalanceTime(hour, minute, second, millisecond, microsecond, result).
-            TemporalUnit::Nanosecond => Ok(Self::balance(
+            TemporalUnit::Nanosecond => Self::balance(
                 self.hour.into(),
                 self.minute.into(),
                 self.second.into(),
                 self.millisecond.into(),
                 self.microsecond.into(),
-                result_f64,
-            )),
+                result,
+            ),
             _ => Err(TemporalError::assert()),
         }
     }
 
     /// Checks if the time is a valid `IsoTime`
-    pub(crate) fn is_valid(&self) -> bool {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
         if !(0..=23).contains(&self.hour) {
             return false;
         }
@@ -850,19 +950,19 @@ impl IsoTime {
             && sub_second.contains(&self.nanosecond)
     }
 
-    pub(crate) fn add(&self, norm: NormalizedTimeDuration) -> (i32, Self) {
+    pub(crate) fn add(&self, norm: NormalizedTimeDuration) -> TemporalResult<(i32, Self)> {
         // 1. Set second to second + NormalizedTimeDurationSeconds(norm).
-        let seconds = f64::from(self.second) + norm.seconds() as f64;
+        let seconds = i128::from(self.second) + i128::from(norm.seconds());
         // 2. Set nanosecond to nanosecond + NormalizedTimeDurationSubseconds(norm).
-        let nanos = i32::from(self.nanosecond) + norm.subseconds();
+        let nanos = i128::from(self.nanosecond) + i128::from(norm.subseconds());
         // 3. Return BalanceTime(hour, minute, second, millisecond, microsecond, nanosecond).
         Self::balance(
-            f64::from(self.hour),
-            f64::from(self.minute),
+            self.hour.into(),
+            self.minute.into(),
             seconds,
-            f64::from(self.millisecond),
-            f64::from(self.microsecond),
-            f64::from(nanos),
+            self.millisecond.into(),
+            self.microsecond.into(),
+            nanos,
         )
     }
 
@@ -884,19 +984,23 @@ impl IsoTime {
 #[inline]
 /// Utility function to determine if a `DateTime`'s components create a `DateTime` within valid limits
 fn iso_dt_within_valid_limits(date: IsoDate, time: &IsoTime) -> bool {
-    if iso_date_to_epoch_days(date.year, (date.month - 1).into(), date.day.into()).abs()
-        > 100_000_001
-    {
+    // 1. If abs(ISODateToEpochDays(isoDateTime.[[ISODate]].[[Year]], isoDateTime.[[ISODate]].[[Month]] - 1,
+    // isoDateTime.[[ISODate]].[[Day]])) > 10**8 + 1, return false.
+    let epoch_days = date.to_epoch_days();
+    if epoch_days.abs() > 100_000_001 {
         return false;
     }
-    let Some(ns) = utc_epoch_nanos(date, time, 0.0) else {
-        return false;
-    };
-
-    let max = BigInt::from(crate::NS_MAX_INSTANT + i128::from(NS_PER_DAY));
-    let min = BigInt::from(crate::NS_MIN_INSTANT - i128::from(NS_PER_DAY));
-
-    min <= ns && max >= ns
+    // 2. Let ns be ℝ(GetUTCEpochNanoseconds(isoDateTime)).
+    // NOTE: The epoch nanoseconds are calculated with integers, as an `f64` cannot represent
+    // every nanosecond near the limits.
+    let ns = i128::from(epoch_days) * i128::from(NS_PER_DAY)
+        + i128::from(time.to_nanoseconds_since_midnight());
+
+    // 3. If ns ≤ nsMinInstant - nsPerDay, return false.
+    // 4. If ns ≥ nsMaxInstant + nsPerDay, return false.
+    // 5. Return true.
+    ns > crate::NS_MIN_INSTANT - i128::from(NS_PER_DAY)
+        && ns < crate::NS_MAX_INSTANT + i128::from(NS_PER_DAY)
 }
 
 #[inline]
@@ -975,22 +1079,34 @@ pub(crate) fn is_valid_iso_day(year: i32, month: i32, day: i32) -> bool {
 // ==== `IsoTime` specific utilities ====
 
 #[inline]
-fn is_valid_time(hour: i32, minute: i32, second: i32, ms: i32, mis: i32, ns: i32) -> bool {
-    if !(0..=23).contains(&hour) {
-        return false;
-    }
-
-    let min_sec = 0..=59;
-    if !min_sec.contains(&minute) || !min_sec.contains(&second) {
-        return false;
-    }
-
-    let sub_second = 0..=999;
-    sub_second.contains(&ms) && sub_second.contains(&mis) && sub_second.contains(&ns)
+fn time_balance_range_error() -> TemporalError {
+    TemporalError::range().with_message("Balanced time values exceed the supported range.")
 }
 
-// NOTE(nekevss): Considering the below: Balance can probably be altered from f64.
+/// Validates the time fields, returning an error naming the first field that is out of range.
 #[inline]
-fn div_mod(dividend: f64, divisor: f64) -> (f64, f64) {
-    (dividend.div_euclid(divisor), dividend.rem_euclid(divisor))
+fn validate_time_fields(
+    hour: i32,
+    minute: i32,
+    second: i32,
+    ms: i32,
+    mis: i32,
+    ns: i32,
+) -> TemporalResult<()> {
+    let fields = [
+        ("hour", hour, 23),
+        ("minute", minute, 59),
+        ("second", second, 59),
+        ("millisecond", ms, 999),
+        ("microsecond", mis, 999),
+        ("nanosecond", ns, 999),
+    ];
+    for (name, value, max) in fields {
+        if !(0..=max).contains(&value) {
+            return Err(TemporalError::range().with_message(format!(
+                "{name} value {value} is not within the range 0-{max}."
+            )));
+        }
+    }
+    Ok(())
 }
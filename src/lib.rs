@@ -11,6 +11,14 @@
 //! This library's primary source is the Temporal Proposal
 //! [specification][spec].
 //!
+//! ## Crate features
+//!
+//! - `std` (default): enables conversions from `std::time::SystemTime` and the
+//!   `std::error::Error` implementation of `TemporalError`.
+//! - `chrono`: enables conversions between Temporal components and `chrono` types.
+//!
+//! NOTE: The crate currently always links `std`, so `no_std` targets are not supported.
+//!
 //! [proposal]: https://github.com/tc39/proposal-temporal
 //! [spec]: https://tc39.es/proposal-temporal/
 #![doc(
@@ -43,24 +51,24 @@ pub mod error;
 pub mod options;
 pub mod parsers;
 pub mod primitive;
+pub mod timescales;
+
+pub mod iso;
 
 pub(crate) mod components;
-pub(crate) mod iso;
 
 #[doc(hidden)]
 pub(crate) mod rounding;
 #[doc(hidden)]
 pub(crate) mod utils;
 
-use std::cmp::Ordering;
-
 // TODO: evaluate positives and negatives of using tinystr. Re-exporting
 // tinystr as a convenience, as it is currently tied into the API.
 /// Re-export of `TinyAsciiStr` from `tinystr`.
 pub use tinystr::TinyAsciiStr;
 
 #[doc(inline)]
-pub use error::TemporalError;
+pub use error::{ErrorKind, TemporalError};
 
 /// The `Temporal` result type
 pub type TemporalResult<T> = Result<T, TemporalError>;
@@ -76,8 +84,10 @@ pub mod partial {
 }
 
 pub use crate::components::{
-    calendar::Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime,
-    PlainYearMonth, ZonedDateTime,
+    calendar::Calendar,
+    duration::{DateDuration, TimeDuration},
+    Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
+    WeekInfo, Weekday, ZonedDateTime,
 };
 
 /// A library specific trait for unwrapping assertions.
@@ -127,15 +137,53 @@ pub enum Sign {
 
 impl From<i8> for Sign {
     fn from(value: i8) -> Self {
-        match value.cmp(&0) {
-            Ordering::Greater => Self::Positive,
-            Ordering::Equal => Self::Zero,
-            Ordering::Less => Self::Negative,
-        }
+        Self::from_i8(value)
     }
 }
 
 impl Sign {
+    /// Creates a `Sign` from the sign of an `i8`.
+    #[inline]
+    #[must_use]
+    pub const fn from_i8(value: i8) -> Self {
+        match value {
+            1..=i8::MAX => Self::Positive,
+            0 => Self::Zero,
+            i8::MIN..=-1 => Self::Negative,
+        }
+    }
+
+    /// Returns the current `Sign` as an `i8` of `1`, `0`, or `-1`.
+    #[inline]
+    #[must_use]
+    pub const fn as_i8(self) -> i8 {
+        self as i8
+    }
+
+    /// Returns the opposite `Sign`. `Zero` is its own negation.
+    #[inline]
+    #[must_use]
+    pub const fn negate(self) -> Self {
+        match self {
+            Self::Positive => Self::Negative,
+            Self::Zero => Self::Zero,
+            Self::Negative => Self::Positive,
+        }
+    }
+
+    /// Applies the current `Sign` to a value.
+    ///
+    /// `Negative` negates the value, `Positive` returns it unchanged, and `Zero` returns zero.
+    #[inline]
+    #[must_use]
+    pub fn apply_to(self, value: f64) -> f64 {
+        match self {
+            Self::Positive => value,
+            Self::Zero => 0.0,
+            Self::Negative => -value,
+        }
+    }
+
     /// Coerces the current `Sign` to be either negative or positive.
     pub(crate) fn as_sign_multiplier(&self) -> i8 {
         if matches!(self, Self::Zero) {
@@ -150,9 +198,36 @@ impl Sign {
 pub const NS_PER_DAY: u64 = MS_PER_DAY as u64 * 1_000_000;
 /// Milliseconds per day constant: 8.64e+7
 pub const MS_PER_DAY: u32 = 24 * 60 * 60 * 1000;
+/// Nanoseconds per second constant: 1e+9
+pub(crate) const NS_PER_SECOND: u32 = 1_000_000_000;
 /// Max Instant nanosecond constant
 #[doc(hidden)]
 pub(crate) const NS_MAX_INSTANT: i128 = NS_PER_DAY as i128 * 100_000_000i128;
 /// Min Instant nanosecond constant
 #[doc(hidden)]
 pub(crate) const NS_MIN_INSTANT: i128 = -NS_MAX_INSTANT;
+
+#[cfg(test)]
+mod tests {
+    use super::Sign;
+
+    #[test]
+    fn sign_conversions() {
+        assert_eq!(Sign::from_i8(i8::MAX), Sign::Positive);
+        assert_eq!(Sign::from_i8(0), Sign::Zero);
+        assert_eq!(Sign::from(i8::MIN), Sign::Negative);
+        assert_eq!(Sign::Negative.as_i8(), -1);
+        assert_eq!(Sign::Zero.as_i8(), 0);
+    }
+
+    #[test]
+    fn sign_negate_and_apply() {
+        assert_eq!(Sign::Positive.negate(), Sign::Negative);
+        assert_eq!(Sign::Negative.negate(), Sign::Positive);
+        assert_eq!(Sign::Zero.negate(), Sign::Zero);
+
+        assert_eq!(Sign::Positive.apply_to(2.5), 2.5);
+        assert_eq!(Sign::Negative.apply_to(2.5), -2.5);
+        assert_eq!(Sign::Zero.apply_to(2.5), 0.0);
+    }
+}
@@ -3,11 +3,12 @@
 //! Temporal has various instances where user's can define options for how an
 //! operation may be completed.
 
+use core::ops::Add;
 use core::{fmt, str::FromStr};
-use std::ops::Add;
 
 use crate::{
     components::{PlainDate, ZonedDateTime},
+    parsers::parse_annotations,
     Sign, TemporalError, TemporalResult, MS_PER_DAY, NS_PER_DAY,
 };
 
@@ -31,6 +32,50 @@ pub struct DifferenceSettings {
     pub increment: Option<RoundingIncrement>,
 }
 
+impl DifferenceSettings {
+    /// Creates a new `DifferenceSettings` with all settings unset.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the largest unit of the difference.
+    ///
+    /// The largest unit must not be smaller than the smallest unit, which is validated when the
+    /// settings are used.
+    #[inline]
+    #[must_use]
+    pub fn largest_unit(mut self, unit: TemporalUnit) -> Self {
+        self.largest_unit = Some(unit);
+        self
+    }
+
+    /// Sets the smallest unit of the difference.
+    #[inline]
+    #[must_use]
+    pub fn smallest_unit(mut self, unit: TemporalUnit) -> Self {
+        self.smallest_unit = Some(unit);
+        self
+    }
+
+    /// Sets the rounding mode of the difference.
+    #[inline]
+    #[must_use]
+    pub fn rounding_mode(mut self, mode: TemporalRoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+
+    /// Sets the rounding increment of the difference.
+    #[inline]
+    #[must_use]
+    pub fn increment(mut self, increment: RoundingIncrement) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct RoundingOptions {
@@ -54,9 +99,9 @@ impl Default for RoundingOptions {
     }
 }
 
-/// Internal options object that represents the resolved rounding options.
+/// The rounding options that an operation resolved and used after applying its defaults.
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct ResolvedRoundingOptions {
+pub struct ResolvedRoundingOptions {
     pub(crate) largest_unit: TemporalUnit,
     pub(crate) smallest_unit: TemporalUnit,
     pub(crate) increment: RoundingIncrement,
@@ -64,6 +109,83 @@ pub(crate) struct ResolvedRoundingOptions {
 }
 
 impl ResolvedRoundingOptions {
+    /// Returns the resolved largest unit.
+    #[inline]
+    #[must_use]
+    pub const fn largest_unit(&self) -> TemporalUnit {
+        self.largest_unit
+    }
+
+    /// Returns the resolved smallest unit.
+    #[inline]
+    #[must_use]
+    pub const fn smallest_unit(&self) -> TemporalUnit {
+        self.smallest_unit
+    }
+
+    /// Returns the resolved rounding increment.
+    #[inline]
+    #[must_use]
+    pub const fn increment(&self) -> RoundingIncrement {
+        self.increment
+    }
+
+    /// Returns the resolved rounding mode.
+    ///
+    /// For a `since` operation, this is the negation of the provided rounding mode.
+    #[inline]
+    #[must_use]
+    pub const fn rounding_mode(&self) -> TemporalRoundingMode {
+        self.rounding_mode
+    }
+}
+
+impl ResolvedRoundingOptions {
+    /// Resolves `RoundingOptions` so that the result can be reused across rounding operations,
+    /// e.g. with `PlainTime::round_many`.
+    ///
+    /// The following defaults are applied:
+    ///
+    /// - An unset smallest unit is `fallback_smallest`.
+    /// - An unset or `Auto` largest unit is the larger of `fallback_largest` and the smallest unit.
+    /// - An unset rounding mode is `HalfExpand`.
+    /// - An unset increment is `1`.
+    ///
+    /// The largest unit must not be smaller than the smallest unit, and the increment must be
+    /// valid for the smallest unit.
+    pub fn from_options(
+        options: RoundingOptions,
+        fallback_smallest: TemporalUnit,
+        fallback_largest: TemporalUnit,
+    ) -> TemporalResult<Self> {
+        let increment = options.increment.unwrap_or_default();
+        let rounding_mode = options.rounding_mode.unwrap_or_default();
+        let smallest_unit = options.smallest_unit.unwrap_or(fallback_smallest);
+        let default_largest = fallback_largest.max(smallest_unit);
+
+        let largest_unit = match options.largest_unit {
+            Some(TemporalUnit::Auto) | None => default_largest,
+            Some(unit) => unit,
+        };
+
+        if largest_unit < smallest_unit {
+            return Err(TemporalError::range().with_message(format!(
+                "largestUnit \"{largest_unit}\" cannot be smaller than smallestUnit \"{smallest_unit}\"."
+            )));
+        }
+
+        if let Some(max) = smallest_unit.to_maximum_rounding_increment() {
+            increment.validate(max.into(), false)?;
+        }
+
+        Ok(Self {
+            largest_unit,
+            smallest_unit,
+            increment,
+            rounding_mode,
+        })
+    }
+
     pub(crate) fn from_diff_settings(
         options: DifferenceSettings,
         operation: DifferenceOperation,
@@ -88,9 +210,18 @@ impl ResolvedRoundingOptions {
         };
         let smallest_unit = options.smallest_unit.unwrap_or(fallback_smallest);
         // Use the defaultlargestunit which is max smallestlargestdefault and smallestunit
-        let largest_unit = options
-            .largest_unit
-            .unwrap_or(smallest_unit.max(fallback_largest));
+        let default_largest = smallest_unit.max(fallback_largest);
+        let largest_unit = match options.largest_unit {
+            Some(TemporalUnit::Auto) | None => default_largest,
+            Some(unit) => unit,
+        };
+
+        // If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if largest_unit < smallest_unit {
+            return Err(TemporalError::range().with_message(format!(
+                "largestUnit \"{largest_unit}\" cannot be smaller than smallestUnit \"{smallest_unit}\"."
+            )));
+        }
 
         let resolved = ResolvedRoundingOptions {
             largest_unit,
@@ -114,9 +245,7 @@ impl ResolvedRoundingOptions {
         }
 
         // 14. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
-        let increment = options.increment.unwrap_or_default();
         // 15. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
-        let rounding_mode = options.rounding_mode.unwrap_or_default();
         // 16. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", DATETIME, undefined).
         // 17. If smallestUnit is undefined, then
         // a. Set smallestUnitPresent to false.
@@ -134,33 +263,7 @@ impl ResolvedRoundingOptions {
         // 23. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
         // 24. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
         // 25. If maximum is not undefined, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
-        let smallest_unit = options.smallest_unit.unwrap_or(TemporalUnit::Nanosecond);
-
-        let default_largest = existing_largest.max(smallest_unit);
-
-        let largest_unit = match options.largest_unit {
-            Some(TemporalUnit::Auto) | None => default_largest,
-            Some(unit) => unit,
-        };
-
-        if largest_unit.max(smallest_unit) != largest_unit {
-            return Err(TemporalError::range().with_message(
-                "largestUnit when rounding Duration was not the largest provided unit",
-            ));
-        }
-
-        let maximum = smallest_unit.to_maximum_rounding_increment();
-        // 25. If maximum is not undefined, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
-        if let Some(max) = maximum {
-            increment.validate(max.into(), false)?;
-        }
-
-        Ok(Self {
-            largest_unit,
-            smallest_unit,
-            increment,
-            rounding_mode,
-        })
+        Self::from_options(options, TemporalUnit::Nanosecond, existing_largest)
     }
 
     // NOTE: Should the GetTemporalUnitValuedOption check be integrated into these validations.
@@ -168,14 +271,11 @@ impl ResolvedRoundingOptions {
         let increment = options.increment.unwrap_or_default();
         let rounding_mode = options.rounding_mode.unwrap_or_default();
         let smallest_unit = options.smallest_unit.unwrap_or(TemporalUnit::Day);
-        let (maximum, inclusive) = if smallest_unit == TemporalUnit::Day {
-            (1, true)
-        } else {
-            let maximum = smallest_unit
-                .to_maximum_rounding_increment()
-                .ok_or(TemporalError::range().with_message("smallestUnit must be a time unit."))?;
-            (maximum, false)
-        };
+        let (maximum, inclusive) = smallest_unit
+            .to_maximum_date_time_rounding_increment()
+            .ok_or(
+                TemporalError::range().with_message("smallestUnit must be a time unit or day."),
+            )?;
 
         increment.validate(maximum.into(), inclusive)?;
 
@@ -204,6 +304,10 @@ impl ResolvedRoundingOptions {
             _ => return Err(TemporalError::range().with_message("Invalid roundTo unit provided.")),
         };
 
+        // NOTE: Unlike a `PlainTime`, whose increments must be strictly less than the unit's wrap
+        // point, the maximum is inclusive. The maximum is the number of units in a day, so an
+        // `Instant` may be rounded to a whole day, i.e. to midnight UTC. A `PlainTime` rounded to a
+        // whole day would always wrap around to midnight, so the spec rejects that increment.
         increment.validate(maximum, true)?;
 
         Ok(Self {
@@ -219,11 +323,173 @@ impl ResolvedRoundingOptions {
     }
 }
 
+// ==== ToStringRoundingOptions ====
+
+/// The precision of the seconds of a formatted time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Seconds with the trailing zeros of the fraction omitted.
+    #[default]
+    Auto,
+    /// No seconds, e.g. `12:05`.
+    Minute,
+    /// Seconds with a fixed number of fractional digits, from 0 to 9.
+    Digit(u8),
+}
+
+/// The options of a `toString` operation, combining the `fractionalSecondDigits`,
+/// `smallestUnit` and `roundingMode` options.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ToStringRoundingOptions {
+    pub precision: Precision,
+    pub smallest_unit: Option<TemporalUnit>,
+    pub rounding_mode: Option<TemporalRoundingMode>,
+}
+
+/// The precision and rounding that a `toString` operation resolved from its options.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedToStringRoundingOptions {
+    pub precision: Precision,
+    pub smallest_unit: TemporalUnit,
+    pub increment: RoundingIncrement,
+    pub rounding_mode: TemporalRoundingMode,
+}
+
+impl ToStringRoundingOptions {
+    /// Resolves the effective precision and rounding of the options.
+    ///
+    /// The `smallest_unit` must be a time unit from `minute` to `nanosecond`, and a fixed
+    /// precision must be at most 9 digits. When both a `smallest_unit` and a precision other
+    /// than `Auto` are provided, they must describe the same precision, e.g. `millisecond` and
+    /// `Digit(3)`, or `minute` and `Minute`. An unset rounding mode is `Trunc`.
+    ///
+    /// Temporal-Proposal equivalent: `ToSecondsStringPrecisionRecord`.
+    pub fn resolve(self) -> TemporalResult<ResolvedToStringRoundingOptions> {
+        let rounding_mode = self.rounding_mode.unwrap_or(TemporalRoundingMode::Trunc);
+        if let Precision::Digit(digits) = self.precision {
+            if digits > 9 {
+                return Err(TemporalError::range()
+                    .with_message("fractionalSecondDigits must be an integer from 0 to 9."));
+            }
+        }
+
+        let Some(smallest_unit) = self.smallest_unit else {
+            let (smallest_unit, increment) = match self.precision {
+                Precision::Auto => (TemporalUnit::Nanosecond, 1),
+                Precision::Minute => (TemporalUnit::Minute, 1),
+                Precision::Digit(0) => (TemporalUnit::Second, 1),
+                Precision::Digit(d @ 1..=3) => {
+                    (TemporalUnit::Millisecond, 10u32.pow(3 - u32::from(d)))
+                }
+                Precision::Digit(d @ 4..=6) => {
+                    (TemporalUnit::Microsecond, 10u32.pow(6 - u32::from(d)))
+                }
+                Precision::Digit(d) => (TemporalUnit::Nanosecond, 10u32.pow(9 - u32::from(d))),
+            };
+            return Ok(ResolvedToStringRoundingOptions {
+                precision: self.precision,
+                smallest_unit,
+                increment: RoundingIncrement::try_new(increment)?,
+                rounding_mode,
+            });
+        };
+
+        let unit_precision = match smallest_unit {
+            TemporalUnit::Minute => Precision::Minute,
+            TemporalUnit::Second => Precision::Digit(0),
+            TemporalUnit::Millisecond => Precision::Digit(3),
+            TemporalUnit::Microsecond => Precision::Digit(6),
+            TemporalUnit::Nanosecond => Precision::Digit(9),
+            _ => {
+                return Err(TemporalError::range().with_message(
+                    "smallestUnit must be a time unit from minute to nanosecond for a string.",
+                ))
+            }
+        };
+        if self.precision != Precision::Auto && self.precision != unit_precision {
+            return Err(TemporalError::range().with_message(format!(
+                "smallestUnit \"{smallest_unit}\" conflicts with the provided fractionalSecondDigits."
+            )));
+        }
+
+        Ok(ResolvedToStringRoundingOptions {
+            precision: unit_precision,
+            smallest_unit,
+            increment: RoundingIncrement::ONE,
+            rounding_mode,
+        })
+    }
+}
+
 // ==== RelativeTo Object ====
 
-pub struct RelativeTo<'a> {
-    pub date: Option<&'a PlainDate>,
-    pub zdt: Option<&'a ZonedDateTime>,
+/// The `relativeTo` anchor of calendar-aware `Duration` operations.
+///
+/// Days are 24 hours long relative to a `PlainDate`, while relative to a `ZonedDateTime` they
+/// are resolved in its time zone using exact time.
+#[derive(Debug, Clone)]
+pub enum RelativeTo {
+    /// A `PlainDate` anchor.
+    PlainDate(PlainDate),
+    /// A `ZonedDateTime` anchor.
+    ZonedDateTime(ZonedDateTime),
+}
+
+impl RelativeTo {
+    /// Returns the `PlainDate` anchor, if this is a `PlainDate` variant.
+    #[inline]
+    #[must_use]
+    pub fn as_plain_date(&self) -> Option<&PlainDate> {
+        match self {
+            Self::PlainDate(date) => Some(date),
+            Self::ZonedDateTime(_) => None,
+        }
+    }
+
+    /// Returns the `ZonedDateTime` anchor, if this is a `ZonedDateTime` variant.
+    #[inline]
+    #[must_use]
+    pub fn as_zoned_date_time(&self) -> Option<&ZonedDateTime> {
+        match self {
+            Self::PlainDate(_) => None,
+            Self::ZonedDateTime(zdt) => Some(zdt),
+        }
+    }
+
+    /// Returns the `ZonedDateTime` anchor for an operation that requires one, or a `RangeError`
+    /// for a `PlainDate` anchor.
+    #[inline]
+    pub fn zoned_date_time(&self) -> TemporalResult<&ZonedDateTime> {
+        self.as_zoned_date_time().ok_or_else(|| {
+            TemporalError::range()
+                .with_message("This operation requires a ZonedDateTime relativeTo.")
+        })
+    }
+}
+
+impl From<PlainDate> for RelativeTo {
+    fn from(value: PlainDate) -> Self {
+        Self::PlainDate(value)
+    }
+}
+
+impl From<ZonedDateTime> for RelativeTo {
+    fn from(value: ZonedDateTime) -> Self {
+        Self::ZonedDateTime(value)
+    }
+}
+
+impl FromStr for RelativeTo {
+    type Err = TemporalError;
+
+    /// Parses a `relativeTo` string, which is a `ZonedDateTime` when it contains a time zone
+    /// annotation, e.g. `2024-01-01T00:00-05:00[-05:00]`, and a `PlainDate` otherwise.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if parse_annotations(s)?.time_zone.is_some() {
+            return ZonedDateTime::from_str(s).map(Self::ZonedDateTime);
+        }
+        PlainDate::from_str(s).map(Self::PlainDate)
+    }
 }
 
 // ==== Options enums and methods ====
@@ -257,9 +523,33 @@ pub enum TemporalUnit {
 }
 
 impl TemporalUnit {
+    /// Returns the plural form of this unit, e.g. `"hours"`, as accepted by `from_str`.
+    ///
+    /// `Auto` has no plural form and returns `"auto"`.
+    #[inline]
+    #[must_use]
+    pub const fn plural(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Year => "years",
+            Self::Month => "months",
+            Self::Week => "weeks",
+            Self::Day => "days",
+            Self::Hour => "hours",
+            Self::Minute => "minutes",
+            Self::Second => "seconds",
+            Self::Millisecond => "milliseconds",
+            Self::Microsecond => "microseconds",
+            Self::Nanosecond => "nanoseconds",
+        }
+    }
+
     #[inline]
     #[must_use]
     /// Returns the `MaximumRoundingIncrement` for the current `TemporalUnit`.
+    ///
+    /// The maximum is exclusive, so an increment must be strictly less than it. Returns `None` for
+    /// date units, which have no maximum, and for `Auto`.
     pub fn to_maximum_rounding_increment(self) -> Option<u32> {
         use TemporalUnit::{
             Auto, Day, Hour, Microsecond, Millisecond, Minute, Month, Nanosecond, Second, Week,
@@ -274,16 +564,30 @@ impl TemporalUnit {
         // 4. Assert: unit is one of "millisecond", "microsecond", or "nanosecond".
         // 5. Return 1000.
         let max = match self {
-            Year | Month | Week | Day => return None,
+            Year | Month | Week | Day | Auto => return None,
             Hour => 24,
             Minute | Second => 60,
             Millisecond | Microsecond | Nanosecond => 1000,
-            Auto => unreachable!(),
         };
 
         Some(max)
     }
 
+    /// Returns the maximum rounding increment for the current `TemporalUnit` when rounding a
+    /// `PlainDateTime`, along with whether the maximum is inclusive.
+    ///
+    /// Unlike `to_maximum_rounding_increment`, `Day` is valid in a date-time context and allows
+    /// only an increment of exactly 1. Returns `None` for calendar units and `Auto`.
+    #[inline]
+    #[must_use]
+    pub fn to_maximum_date_time_rounding_increment(self) -> Option<(u32, bool)> {
+        if self == TemporalUnit::Day {
+            return Some((1, true));
+        }
+        self.to_maximum_rounding_increment()
+            .map(|maximum| (maximum, false))
+    }
+
     // TODO: potentiall use a u64
     /// Returns the `Nanosecond amount for any given value.`
     #[must_use]
@@ -304,11 +608,45 @@ impl TemporalUnit {
         }
     }
 
+    /// Returns whether the unit is a calendar unit, i.e. `Year`, `Month`, or `Week`, whose length
+    /// depends on the calendar and a `relativeTo`.
     #[must_use]
     pub fn is_calendar_unit(&self) -> bool {
         use TemporalUnit::{Month, Week, Year};
         matches!(self, Year | Month | Week)
     }
+
+    /// Returns whether the unit is a date unit, i.e. `Year`, `Month`, `Week`, or `Day`.
+    ///
+    /// Temporal equivalent: `TemporalUnitCategory(unit)` is date.
+    #[inline]
+    #[must_use]
+    pub fn is_date_unit(&self) -> bool {
+        self.is_calendar_unit() || *self == TemporalUnit::Day
+    }
+
+    /// Returns whether the unit is a time unit, i.e. `Hour` through `Nanosecond`.
+    ///
+    /// Temporal equivalent: `TemporalUnitCategory(unit)` is time.
+    #[inline]
+    #[must_use]
+    pub fn is_time_unit(&self) -> bool {
+        use TemporalUnit::{Hour, Microsecond, Millisecond, Minute, Nanosecond, Second};
+        matches!(
+            self,
+            Hour | Minute | Second | Millisecond | Microsecond | Nanosecond
+        )
+    }
+
+    /// Returns the coarseness of the unit, from `0` for `Auto` and `1` for `Nanosecond` up to
+    /// `10` for `Year`.
+    ///
+    /// A larger rank is a coarser unit, which matches the `Ord` implementation of `TemporalUnit`.
+    #[inline]
+    #[must_use]
+    pub const fn rank(self) -> u8 {
+        self as u8
+    }
 }
 
 impl From<usize> for TemporalUnit {
@@ -342,7 +680,7 @@ impl Add<usize> for TemporalUnit {
 pub struct ParseTemporalUnitError;
 
 impl fmt::Display for ParseTemporalUnitError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("provided string was not a valid TemporalUnit")
     }
 }
@@ -369,7 +707,7 @@ impl FromStr for TemporalUnit {
 }
 
 impl fmt::Display for TemporalUnit {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Auto => "auto",
             Self::Year => "year",
@@ -379,7 +717,7 @@ impl fmt::Display for TemporalUnit {
             Self::Hour => "hour",
             Self::Minute => "minute",
             Self::Second => "second",
-            Self::Millisecond => "millsecond",
+            Self::Millisecond => "millisecond",
             Self::Microsecond => "microsecond",
             Self::Nanosecond => "nanosecond",
         }
@@ -403,7 +741,7 @@ pub enum ArithmeticOverflow {
 pub struct ParseArithmeticOverflowError;
 
 impl fmt::Display for ParseArithmeticOverflowError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("provided string was not a valid overflow value")
     }
 }
@@ -421,7 +759,7 @@ impl FromStr for ArithmeticOverflow {
 }
 
 impl fmt::Display for ArithmeticOverflow {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Constrain => "constrain",
             Self::Reject => "reject",
@@ -444,7 +782,7 @@ pub enum DurationOverflow {
 pub struct ParseDurationOverflowError;
 
 impl fmt::Display for ParseDurationOverflowError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("provided string was not a valid duration overflow value")
     }
 }
@@ -462,7 +800,7 @@ impl FromStr for DurationOverflow {
 }
 
 impl fmt::Display for DurationOverflow {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Constrain => "constrain",
             Self::Balance => "balance",
@@ -489,7 +827,7 @@ pub enum InstantDisambiguation {
 pub struct ParseInstantDisambiguationError;
 
 impl fmt::Display for ParseInstantDisambiguationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("provided string was not a valid instant disambiguation value")
     }
 }
@@ -509,7 +847,7 @@ impl FromStr for InstantDisambiguation {
 }
 
 impl fmt::Display for InstantDisambiguation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Compatible => "compatible",
             Self::Earlier => "earlier",
@@ -538,7 +876,7 @@ pub enum OffsetDisambiguation {
 pub struct ParseOffsetDisambiguationError;
 
 impl fmt::Display for ParseOffsetDisambiguationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("provided string was not a valid offset disambiguation value")
     }
 }
@@ -558,7 +896,7 @@ impl FromStr for OffsetDisambiguation {
 }
 
 impl fmt::Display for OffsetDisambiguation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Use => "use",
             Self::Prefer => "prefer",
@@ -644,7 +982,8 @@ impl TemporalRoundingMode {
             Ceil if is_positive => TemporalUnsignedRoundingMode::Infinity,
             Ceil => TemporalUnsignedRoundingMode::Zero,
             Floor if is_positive => TemporalUnsignedRoundingMode::Zero,
-            Floor | Trunc | Expand => TemporalUnsignedRoundingMode::Infinity,
+            Floor | Expand => TemporalUnsignedRoundingMode::Infinity,
+            Trunc => TemporalUnsignedRoundingMode::Zero,
             HalfCeil if is_positive => TemporalUnsignedRoundingMode::HalfInfinity,
             HalfCeil | HalfTrunc => TemporalUnsignedRoundingMode::HalfZero,
             HalfFloor if is_positive => TemporalUnsignedRoundingMode::HalfZero,
@@ -674,7 +1013,7 @@ impl FromStr for TemporalRoundingMode {
 }
 
 impl fmt::Display for TemporalRoundingMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Ceil => "ceil",
             Self::Floor => "floor",
@@ -705,7 +1044,7 @@ pub enum CalendarName {
 }
 
 impl fmt::Display for CalendarName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             CalendarName::Auto => "auto",
             CalendarName::Always => "always",
@@ -729,3 +1068,198 @@ impl FromStr for CalendarName {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::{
+        Precision, RoundingIncrement, TemporalRoundingMode, TemporalUnit, ToStringRoundingOptions,
+    };
+    use crate::ErrorKind;
+
+    #[test]
+    fn maximum_rounding_increments() {
+        let time_units = [
+            (TemporalUnit::Hour, 24),
+            (TemporalUnit::Minute, 60),
+            (TemporalUnit::Second, 60),
+            (TemporalUnit::Millisecond, 1000),
+            (TemporalUnit::Microsecond, 1000),
+            (TemporalUnit::Nanosecond, 1000),
+        ];
+        for (unit, max) in time_units {
+            assert_eq!(unit.to_maximum_rounding_increment(), Some(max));
+            assert_eq!(
+                unit.to_maximum_date_time_rounding_increment(),
+                Some((max, false))
+            );
+        }
+
+        // Day is only a valid rounding unit in a date-time context.
+        assert_eq!(TemporalUnit::Day.to_maximum_rounding_increment(), None);
+        assert_eq!(
+            TemporalUnit::Day.to_maximum_date_time_rounding_increment(),
+            Some((1, true))
+        );
+
+        for unit in [
+            TemporalUnit::Week,
+            TemporalUnit::Month,
+            TemporalUnit::Year,
+            TemporalUnit::Auto,
+        ] {
+            assert_eq!(unit.to_maximum_rounding_increment(), None);
+            assert_eq!(unit.to_maximum_date_time_rounding_increment(), None);
+        }
+    }
+
+    #[test]
+    fn temporal_unit_categories_and_rank() {
+        let units = [
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Microsecond,
+            TemporalUnit::Millisecond,
+            TemporalUnit::Second,
+            TemporalUnit::Minute,
+            TemporalUnit::Hour,
+            TemporalUnit::Day,
+            TemporalUnit::Week,
+            TemporalUnit::Month,
+            TemporalUnit::Year,
+        ];
+        for (index, unit) in units.into_iter().enumerate() {
+            assert_eq!(usize::from(unit.rank()), index + 1);
+            assert_eq!(TemporalUnit::from(index + 1), unit);
+            assert_ne!(unit.is_time_unit(), unit.is_date_unit());
+            assert_eq!(unit.is_time_unit(), unit < TemporalUnit::Day);
+        }
+        assert!(TemporalUnit::Day.is_date_unit() && !TemporalUnit::Day.is_calendar_unit());
+        assert!(TemporalUnit::Week.is_calendar_unit());
+        assert_eq!(TemporalUnit::Auto.rank(), 0);
+        assert!(!TemporalUnit::Auto.is_time_unit() && !TemporalUnit::Auto.is_date_unit());
+
+        assert_eq!(TemporalUnit::from_str("hour").unwrap(), TemporalUnit::Hour);
+        assert_eq!(TemporalUnit::from_str("hours").unwrap(), TemporalUnit::Hour);
+        assert_eq!(
+            TemporalUnit::from_str("milliseconds").unwrap(),
+            TemporalUnit::Millisecond
+        );
+        assert!(TemporalUnit::from_str("Hours").is_err());
+        assert!(TemporalUnit::from_str("autos").is_err());
+    }
+
+    #[test]
+    fn resolve_to_string_rounding_options() {
+        let resolve = |precision, smallest_unit| {
+            ToStringRoundingOptions {
+                precision,
+                smallest_unit,
+                rounding_mode: None,
+            }
+            .resolve()
+        };
+        let check = |precision, smallest_unit, expected: (Precision, TemporalUnit, u32)| {
+            let resolved = resolve(precision, smallest_unit).unwrap();
+            assert_eq!(resolved.precision, expected.0);
+            assert_eq!(resolved.smallest_unit, expected.1);
+            assert_eq!(
+                resolved.increment,
+                RoundingIncrement::try_new(expected.2).unwrap()
+            );
+            assert!(matches!(
+                resolved.rounding_mode,
+                TemporalRoundingMode::Trunc
+            ));
+        };
+
+        // The precision alone determines the unit and increment.
+        check(
+            Precision::Auto,
+            None,
+            (Precision::Auto, TemporalUnit::Nanosecond, 1),
+        );
+        check(
+            Precision::Minute,
+            None,
+            (Precision::Minute, TemporalUnit::Minute, 1),
+        );
+        check(
+            Precision::Digit(0),
+            None,
+            (Precision::Digit(0), TemporalUnit::Second, 1),
+        );
+        check(
+            Precision::Digit(2),
+            None,
+            (Precision::Digit(2), TemporalUnit::Millisecond, 10),
+        );
+        check(
+            Precision::Digit(4),
+            None,
+            (Precision::Digit(4), TemporalUnit::Microsecond, 100),
+        );
+        check(
+            Precision::Digit(9),
+            None,
+            (Precision::Digit(9), TemporalUnit::Nanosecond, 1),
+        );
+
+        // A smallest unit determines the precision, and may restate a matching precision.
+        let ms = Some(TemporalUnit::Millisecond);
+        check(
+            Precision::Auto,
+            ms,
+            (Precision::Digit(3), TemporalUnit::Millisecond, 1),
+        );
+        check(
+            Precision::Digit(3),
+            ms,
+            (Precision::Digit(3), TemporalUnit::Millisecond, 1),
+        );
+        let minute = Some(TemporalUnit::Minute);
+        check(
+            Precision::Minute,
+            minute,
+            (Precision::Minute, TemporalUnit::Minute, 1),
+        );
+
+        // Conflicting or invalid options are a RangeError.
+        for (precision, smallest_unit) in [
+            (Precision::Digit(2), ms),
+            (Precision::Minute, ms),
+            (Precision::Digit(0), minute),
+            (Precision::Digit(10), None),
+            (Precision::Auto, Some(TemporalUnit::Hour)),
+            (Precision::Auto, Some(TemporalUnit::Day)),
+        ] {
+            let err = resolve(precision, smallest_unit).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range);
+        }
+    }
+
+    #[test]
+    fn temporal_unit_string_round_trip() {
+        let units = [
+            TemporalUnit::Auto,
+            TemporalUnit::Year,
+            TemporalUnit::Month,
+            TemporalUnit::Week,
+            TemporalUnit::Day,
+            TemporalUnit::Hour,
+            TemporalUnit::Minute,
+            TemporalUnit::Second,
+            TemporalUnit::Millisecond,
+            TemporalUnit::Microsecond,
+            TemporalUnit::Nanosecond,
+        ];
+        for unit in units {
+            assert_eq!(TemporalUnit::from_str(&unit.to_string()).unwrap(), unit);
+            assert_eq!(TemporalUnit::from_str(unit.plural()).unwrap(), unit);
+        }
+        assert_eq!(TemporalUnit::Millisecond.to_string(), "millisecond");
+        assert_eq!(TemporalUnit::Nanosecond.plural(), "nanoseconds");
+        assert_eq!(TemporalUnit::Auto.to_string(), "auto");
+        assert_eq!(TemporalUnit::Auto.plural(), "auto");
+    }
+}
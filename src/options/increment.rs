@@ -1,4 +1,4 @@
-use std::num::{NonZeroU128, NonZeroU32};
+use core::num::{NonZeroU128, NonZeroU32};
 
 use crate::{TemporalError, TemporalResult};
 
@@ -18,6 +18,15 @@ impl Default for RoundingIncrement {
     }
 }
 
+impl TryFrom<u32> for RoundingIncrement {
+    type Error = TemporalError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+// NOTE: The `f64` conversion is kept for hosts passing a JavaScript `Number`.
 impl TryFrom<f64> for RoundingIncrement {
     type Error = TemporalError;
 
@@ -51,6 +60,8 @@ impl RoundingIncrement {
 
     /// Create a new `RoundingIncrement`.
     ///
+    /// This is the preferred constructor, as it avoids the lossy `f64` conversion.
+    ///
     /// # Errors
     ///
     /// - If `increment` is less than 1 or bigger than 10**9.
@@ -95,7 +106,9 @@ impl RoundingIncrement {
 
         // 3. If increment > maximum, throw a RangeError exception.
         if increment > max {
-            return Err(TemporalError::range().with_message("roundingIncrement exceeds maximum"));
+            return Err(TemporalError::range().with_message(format!(
+                "roundingIncrement exceeds the maximum allowed increment of {max}."
+            )));
         }
 
         // 4. If dividend modulo increment ≠ 0, then
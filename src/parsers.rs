@@ -1,9 +1,13 @@
 //! This module implements Temporal Date/Time parsing functionality.
 
+use std::borrow::Cow;
+
 use crate::{TemporalError, TemporalResult, TemporalUnwrap};
 
 use ixdtf::parsers::{
-    records::{Annotation, DateRecord, IxdtfParseRecord, TimeRecord, UTCOffsetRecord},
+    records::{
+        Annotation, DateRecord, IxdtfParseRecord, TimeRecord, TimeZoneAnnotation, UTCOffsetRecord,
+    },
     IxdtfParser,
 };
 
@@ -13,10 +17,47 @@ enum ParseVariant {
     YearMonth,
     MonthDay,
     DateTime,
+    Time,
+}
+
+/// The annotations of a parsed IXDTF string, along with their critical flags.
+///
+/// Unknown annotations are ignored unless they are flagged as critical, e.g. `[!x-foo=bar]`,
+/// in which case parsing fails.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub struct IxdtfAnnotations<'a> {
+    /// The time zone annotation, e.g. `[America/New_York]`, if present.
+    pub time_zone: Option<TimeZoneAnnotation<'a>>,
+    /// The first `u-ca` calendar annotation, e.g. `[!u-ca=hebrew]`, if present.
+    pub calendar: Option<Annotation<'a>>,
+}
+
+impl IxdtfAnnotations<'_> {
+    /// Rejects a critical calendar annotation that is not `iso8601`, as it cannot be honored
+    /// by a type that only supports the ISO calendar.
+    fn check_iso_only_calendar(&self) -> TemporalResult<()> {
+        match &self.calendar {
+            Some(calendar)
+                if calendar.critical && !calendar.value.eq_ignore_ascii_case("iso8601") =>
+            {
+                Err(TemporalError::range()
+                    .with_message("Critical calendar annotation must be iso8601 for this type."))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[inline]
 fn parse_ixdtf(source: &str, variant: ParseVariant) -> TemporalResult<IxdtfParseRecord> {
+    parse_ixdtf_with_annotations(source, variant).map(|(record, _)| record)
+}
+
+fn parse_ixdtf_with_annotations(
+    source: &str,
+    variant: ParseVariant,
+) -> TemporalResult<(IxdtfParseRecord, IxdtfAnnotations)> {
     fn cast_handler<'a>(
         _: &mut IxdtfParser<'a>,
         handler: impl FnMut(Annotation<'a>) -> Option<Annotation<'a>>,
@@ -49,6 +90,7 @@ fn parse_ixdtf(source: &str, variant: ParseVariant) -> TemporalResult<IxdtfParse
         ParseVariant::YearMonth => parser.parse_year_month_with_annotation_handler(handler),
         ParseVariant::MonthDay => parser.parse_month_day_with_annotation_handler(handler),
         ParseVariant::DateTime => parser.parse_with_annotation_handler(handler),
+        ParseVariant::Time => parser.parse_time_with_annotation_handler(handler),
     }
     .map_err(|e| TemporalError::general(format!("{e}")))?;
 
@@ -60,21 +102,62 @@ fn parse_ixdtf(source: &str, variant: ParseVariant) -> TemporalResult<IxdtfParse
     }
 
     // Validate that the DateRecord exists.
-    if record.date.is_none() {
+    if !matches!(variant, ParseVariant::Time) && record.date.is_none() {
         return Err(
             TemporalError::syntax().with_message("DateTime strings must contain a Date value.")
         );
     }
 
-    record.calendar = first_calendar.map(|v| v.value);
+    record.calendar = first_calendar.as_ref().map(|v| v.value);
+    let annotations = IxdtfAnnotations {
+        time_zone: record.tz.clone(),
+        calendar: first_calendar,
+    };
+
+    Ok((record, annotations))
+}
+
+/// A utility function for parsing the annotations of a `DateTime` string.
+///
+/// For example, `2024-03-10T02:30:00[!America/New_York][u-ca=iso8601]` has a critical
+/// time zone annotation and a non-critical calendar annotation.
+#[inline]
+pub fn parse_annotations(source: &str) -> TemporalResult<IxdtfAnnotations> {
+    parse_ixdtf_with_annotations(source, ParseVariant::DateTime).map(|(_, annotations)| annotations)
+}
 
-    Ok(record)
+/// A utility function for parsing a `DateTime` string for a plain type, i.e. a `PlainDate` or
+/// a `PlainDateTime`, along with its annotations.
+///
+/// A numeric UTC offset is allowed and ignored, but a UTC designator, e.g. `12:05Z`, is rejected
+/// as it implies an exact time that a plain type cannot represent.
+#[inline]
+pub(crate) fn parse_plain_date_time(
+    source: &str,
+) -> TemporalResult<(IxdtfParseRecord, IxdtfAnnotations)> {
+    // NOTE: Annotations may contain a `Z`, e.g. `[Europe/Zurich]`, so only the part before
+    // them is checked.
+    let date_time = source.split('[').next().unwrap_or_default();
+    if date_time.contains(['Z', 'z']) {
+        return Err(TemporalError::range()
+            .with_message("UTC designator is not valid for a plain date or date-time string."));
+    }
+    parse_ixdtf_with_annotations(source, ParseVariant::DateTime)
 }
 
-/// A utility function for parsing a `DateTime` string
+/// A utility function for parsing a `ZonedDateTime` string along with its annotations.
+///
+/// The string must contain a time zone annotation, e.g. `2024-03-10T02:30:00-05:00[-05:00]`.
 #[inline]
-pub(crate) fn parse_date_time(source: &str) -> TemporalResult<IxdtfParseRecord> {
-    parse_ixdtf(source, ParseVariant::DateTime)
+pub(crate) fn parse_zoned_date_time(
+    source: &str,
+) -> TemporalResult<(IxdtfParseRecord, IxdtfAnnotations)> {
+    let (record, annotations) = parse_ixdtf_with_annotations(source, ParseVariant::DateTime)?;
+    if annotations.time_zone.is_none() {
+        return Err(TemporalError::range()
+            .with_message("ZonedDateTime strings must contain a time zone annotation."));
+    }
+    Ok((record, annotations))
 }
 
 pub(crate) struct IxdtfParseInstantRecord {
@@ -86,7 +169,8 @@ pub(crate) struct IxdtfParseInstantRecord {
 /// A utility function for parsing an `Instant` string
 #[inline]
 pub(crate) fn parse_instant(source: &str) -> TemporalResult<IxdtfParseInstantRecord> {
-    let record = parse_ixdtf(source, ParseVariant::DateTime)?;
+    let source = truncate_fraction_digits(source);
+    let record = parse_ixdtf(&source, ParseVariant::DateTime)?;
 
     let IxdtfParseRecord {
         date: Some(date),
@@ -103,21 +187,103 @@ pub(crate) fn parse_instant(source: &str) -> TemporalResult<IxdtfParseInstantRec
     Ok(IxdtfParseInstantRecord { date, time, offset })
 }
 
-/// A utility function for parsing a `YearMonth` string
+/// Returns the offset of a parsed `UTCOffsetRecord` in nanoseconds.
 #[inline]
-pub(crate) fn parse_year_month(source: &str) -> TemporalResult<IxdtfParseRecord> {
-    let ym_record = parse_ixdtf(source, ParseVariant::YearMonth);
+pub(crate) fn utc_offset_nanoseconds(offset: &UTCOffsetRecord) -> i64 {
+    i64::from(offset.sign as i8)
+        * (i64::from(offset.hour) * 3_600_000_000_000
+            + i64::from(offset.minute) * 60_000_000_000
+            + i64::from(offset.second) * 1_000_000_000
+            + i64::from(offset.nanosecond))
+}
 
-    if let Ok(ym) = ym_record {
-        return Ok(ym);
+/// Checks that the source follows the RFC 3339 `date-time` grammar, i.e.
+/// `YYYY-MM-DD(T|t| )HH:MM:SS[.fraction](Z|z|±HH:MM)`.
+///
+/// Forms that IXDTF accepts beyond RFC 3339, e.g. the basic format, expanded years, omitted
+/// seconds, a `,` fraction separator, or annotations, are rejected.
+pub(crate) fn check_rfc3339_date_time(source: &str) -> TemporalResult<()> {
+    let bytes = source.as_bytes();
+    let digits = |range: core::ops::Range<usize>| {
+        bytes
+            .get(range)
+            .is_some_and(|digits| digits.iter().all(u8::is_ascii_digit))
+    };
+    let is = |index: usize, expected: &[u8]| {
+        bytes.get(index).is_some_and(|byte| expected.contains(byte))
+    };
+
+    let valid_date_time = digits(0..4)
+        && is(4, b"-")
+        && digits(5..7)
+        && is(7, b"-")
+        && digits(8..10)
+        && is(10, b"Tt ")
+        && digits(11..13)
+        && is(13, b":")
+        && digits(14..16)
+        && is(16, b":")
+        && digits(17..19);
+    if !valid_date_time {
+        return Err(rfc3339_syntax_error());
     }
 
-    let dt_parse = parse_ixdtf(source, ParseVariant::DateTime);
+    let mut rest = &bytes[19..];
+    if let Some((b'.', fraction)) = rest.split_first() {
+        let fraction_digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if fraction_digits == 0 {
+            return Err(rfc3339_syntax_error());
+        }
+        rest = &fraction[fraction_digits..];
+    }
 
-    match dt_parse {
-        Ok(dt) => Ok(dt),
-        // Format and return the error from parsing YearMonth.
-        _ => ym_record.map_err(|e| TemporalError::range().with_message(format!("{e}"))),
+    match rest {
+        [b'Z' | b'z'] => Ok(()),
+        [b'+' | b'-', h1, h2, b':', m1, m2]
+            if [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit()) =>
+        {
+            Ok(())
+        }
+        _ => Err(rfc3339_syntax_error()),
+    }
+}
+
+#[inline]
+fn rfc3339_syntax_error() -> TemporalError {
+    TemporalError::syntax().with_message("String is not a valid RFC 3339 date-time.")
+}
+
+/// Truncates a fraction in the source to nanosecond precision, i.e. nine digits.
+fn truncate_fraction_digits(source: &str) -> Cow<'_, str> {
+    let Some(separator) = source.find(['.', ',']) else {
+        return Cow::Borrowed(source);
+    };
+    let digits = source[separator + 1..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digits <= 9 {
+        return Cow::Borrowed(source);
+    }
+    let mut truncated = String::with_capacity(source.len());
+    truncated.push_str(&source[..=separator + 9]);
+    truncated.push_str(&source[separator + 1 + digits..]);
+    Cow::Owned(truncated)
+}
+
+/// A utility function for parsing a `YearMonth` string
+#[inline]
+pub(crate) fn parse_year_month(source: &str) -> TemporalResult<IxdtfParseRecord> {
+    let ym_record = parse_ixdtf(source, ParseVariant::YearMonth);
+
+    match ym_record {
+        Ok(ym) => {
+            check_short_form_calendar(&ym)?;
+            Ok(ym)
+        }
+        // NOTE: A `YearMonth` string must not contain a day component, so unlike `MonthDay`
+        // there is no fallback to a full `DateTime` string.
+        Err(e) => Err(TemporalError::range().with_message(format!("{e}"))),
     }
 }
 
@@ -127,6 +293,7 @@ pub(crate) fn parse_month_day(source: &str) -> TemporalResult<IxdtfParseRecord>
     let md_record = parse_ixdtf(source, ParseVariant::MonthDay);
 
     if let Ok(md) = md_record {
+        check_short_form_calendar(&md)?;
         return Ok(md);
     }
 
@@ -139,22 +306,195 @@ pub(crate) fn parse_month_day(source: &str) -> TemporalResult<IxdtfParseRecord>
     }
 }
 
+/// Rejects a non-ISO calendar annotation on a `YearMonth` or `MonthDay` string without a full
+/// date, e.g. `--12-25[u-ca=hebrew]`, as the reference ISO date cannot be determined.
+fn check_short_form_calendar(record: &IxdtfParseRecord) -> TemporalResult<()> {
+    match record.calendar {
+        Some(calendar) if !calendar.eq_ignore_ascii_case("iso8601") => Err(TemporalError::range()
+            .with_message(
+                "YearMonth and MonthDay strings without a full date must use the iso8601 calendar.",
+            )),
+        _ => Ok(()),
+    }
+}
+
+/// The record of a parsed `Time` string.
+///
+/// Unlike the resulting `PlainTime`, the record preserves the UTC offset and the
+/// calendar annotation, if they were present in the source string.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct IxdtfParseTimeRecord<'a> {
+    /// The parsed time values.
+    pub time: TimeRecord,
+    /// The parsed UTC offset in nanoseconds, e.g. `-18_000_000_000_000` for `-05:00`, if present.
+    pub offset_nanoseconds: Option<i64>,
+    /// The value of the `u-ca` calendar annotation, e.g. `iso8601`, if present.
+    pub calendar: Option<&'a str>,
+}
+
+/// A utility function for parsing a `Time` string.
+///
+/// The source may either be a standalone time string, e.g. `T12:05:24-05:00[u-ca=iso8601]`,
+/// or a `DateTime` string that contains a time value.
 #[inline]
-pub(crate) fn parse_time(source: &str) -> TemporalResult<TimeRecord> {
-    let time_record = IxdtfParser::new(source).parse_time();
+pub fn parse_time(source: &str) -> TemporalResult<IxdtfParseTimeRecord> {
+    check_time_fraction_placement(source)?;
+
+    let time_record = parse_ixdtf_with_annotations(source, ParseVariant::Time);
 
     let time_err = match time_record {
-        Ok(time) => return time.time.temporal_unwrap(),
-        Err(e) => TemporalError::range().with_message(format!("{e}")),
+        Ok((record, annotations)) => {
+            annotations.check_iso_only_calendar()?;
+            return IxdtfParseTimeRecord {
+                time: record.time.temporal_unwrap()?,
+                offset_nanoseconds: record.offset.as_ref().map(utc_offset_nanoseconds),
+                calendar: record.calendar,
+            }
+            .validated();
+        }
+        Err(e) => TemporalError::range().with_message(e.message()),
     };
 
-    let dt_parse = parse_ixdtf(source, ParseVariant::DateTime);
+    let dt_parse = parse_ixdtf_with_annotations(source, ParseVariant::DateTime);
 
     match dt_parse {
-        Ok(dt) if dt.time.is_some() => Ok(dt.time.temporal_unwrap()?),
+        Ok((dt, annotations)) if dt.time.is_some() => {
+            annotations.check_iso_only_calendar()?;
+            IxdtfParseTimeRecord {
+                time: dt.time.temporal_unwrap()?,
+                offset_nanoseconds: dt.offset.as_ref().map(utc_offset_nanoseconds),
+                calendar: dt.calendar,
+            }
+            .validated()
+        }
         // Format and return the error from parsing Time.
         _ => Err(time_err),
     }
 }
 
+/// Checks that a fraction in a time string only appears on a seconds value.
+///
+/// Non-conformant inputs like `12:30.5` (fractional minutes) or `12.5:00` (fractional
+/// hours) are rejected with a `SyntaxError` instead of being misinterpreted.
+fn check_time_fraction_placement(source: &str) -> TemporalResult<()> {
+    // Annotations may not contain time fractions.
+    let source = source.split('[').next().unwrap_or_default();
+    let Some(separator) = source.find(['.', ',']) else {
+        return Ok(());
+    };
+
+    // Count the digits of the time or offset value that is directly before the fraction.
+    let digits = source[..separator]
+        .bytes()
+        .rev()
+        .take_while(|b| b.is_ascii_digit() || *b == b':')
+        .filter(u8::is_ascii_digit)
+        .count();
+
+    // A fraction is only valid after a full `HH:MM:SS` or `HHMMSS` value.
+    if digits != 6 {
+        return Err(TemporalError::syntax()
+            .with_message("Fractional values are only allowed on the seconds field."));
+    }
+    Ok(())
+}
+
+impl IxdtfParseTimeRecord<'_> {
+    /// Rejects time values that the IXDTF grammar allows, but Temporal does not represent.
+    fn validated(self) -> TemporalResult<Self> {
+        if self.time.second == 60 {
+            return Err(TemporalError::leap_second());
+        }
+        Ok(self)
+    }
+}
+
 // TODO: ParseTimeZoneString, ParseZonedDateTimeString
+
+#[cfg(test)]
+mod tests {
+    use ixdtf::parsers::records::TimeZoneRecord;
+
+    use crate::TemporalError;
+
+    use super::{parse_annotations, parse_time};
+
+    #[test]
+    fn time_record_keeps_offset_and_calendar() {
+        let record = parse_time("T12:05:24-05:00[u-ca=iso8601]").unwrap();
+        assert_eq!(
+            (record.time.hour, record.time.minute, record.time.second),
+            (12, 5, 24)
+        );
+        assert_eq!(record.offset_nanoseconds, Some(-18_000_000_000_000));
+        assert_eq!(record.calendar, Some("iso8601"));
+
+        let record = parse_time("12:05:24").unwrap();
+        assert!(record.offset_nanoseconds.is_none());
+        assert!(record.calendar.is_none());
+    }
+
+    #[test]
+    fn reject_leap_second() {
+        let err = parse_time("23:59:60").unwrap_err();
+        assert_eq!(err, TemporalError::leap_second());
+
+        let record = parse_time("23:59:59.999999999").unwrap();
+        assert_eq!(record.time.second, 59);
+        assert_eq!(record.time.nanosecond, 999_999_999);
+    }
+
+    #[test]
+    fn reject_non_seconds_fraction() {
+        use crate::error::ErrorKind;
+
+        let err = parse_time("12.5:00").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+        let err = parse_time("12:30.5").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+        let err = parse_time("2024-05-04T12:30,5").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+
+        let record = parse_time("12:30:00.5").unwrap();
+        assert_eq!(record.time.nanosecond, 500_000_000);
+        let record = parse_time("T123000.5").unwrap();
+        assert_eq!(record.time.nanosecond, 500_000_000);
+    }
+
+    #[test]
+    fn annotations_keep_critical_flags() {
+        let annotations =
+            parse_annotations("2024-03-10T02:30:00[!America/New_York][u-ca=iso8601]").unwrap();
+        let time_zone = annotations.time_zone.unwrap();
+        assert!(time_zone.critical);
+        assert_eq!(time_zone.tz, TimeZoneRecord::Name("America/New_York"));
+        let calendar = annotations.calendar.unwrap();
+        assert!(!calendar.critical);
+        assert_eq!(calendar.value, "iso8601");
+
+        let annotations = parse_annotations("2024-03-10[!u-ca=hebrew]").unwrap();
+        assert!(annotations.time_zone.is_none());
+        assert!(annotations.calendar.unwrap().critical);
+    }
+
+    #[test]
+    fn unknown_annotations() {
+        // Unknown annotations are ignored, unless they are critical.
+        assert!(parse_annotations("2024-03-10[x-foo=bar]").is_ok());
+        assert!(parse_annotations("2024-03-10[!x-foo=bar]").is_err());
+        // Duplicate calendars are rejected if either is critical.
+        assert!(parse_annotations("2024-03-10[u-ca=iso8601][u-ca=hebrew]").is_ok());
+        assert!(parse_annotations("2024-03-10[u-ca=iso8601][!u-ca=hebrew]").is_err());
+    }
+
+    #[test]
+    fn iso_only_time_rejects_critical_calendar() {
+        assert!(parse_time("T12:00:00[!u-ca=hebrew]").is_err());
+        assert!(parse_time("2024-03-10T12:00:00[!u-ca=hebrew]").is_err());
+        assert!(parse_time("T12:00:00[!u-ca=iso8601]").is_ok());
+
+        let record = parse_time("T12:00:00[u-ca=hebrew]").unwrap();
+        assert_eq!(record.calendar, Some("hebrew"));
+    }
+}
@@ -3,10 +3,16 @@
 use crate::{TemporalError, TemporalResult};
 use num_traits::{AsPrimitive, FromPrimitive};
 
+/// A 64-bit floating point value that is guaranteed to be finite.
+///
+/// A `FiniteF64` can never hold `NaN` or an infinity. Values are validated
+/// on construction with `TryFrom<f64>`, and the checked arithmetic methods
+/// return a `RangeError` rather than producing a non-finite result.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 pub struct FiniteF64(pub(crate) f64);
 
 impl FiniteF64 {
+    /// Returns the inner `f64` value.
     #[inline]
     pub fn as_inner(&self) -> f64 {
         self.0
@@ -31,6 +37,7 @@ impl FiniteF64 {
         Self(self.0.abs())
     }
 
+    /// Adds `other` to this value, returning an error if the result is not finite.
     #[inline]
     pub fn checked_add(&self, other: &Self) -> TemporalResult<Self> {
         let result = Self(self.0 + other.0);
@@ -40,6 +47,26 @@ impl FiniteF64 {
         Ok(result)
     }
 
+    /// Subtracts `other` from this value, returning an error if the result is not finite.
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> TemporalResult<Self> {
+        let result = Self(self.0 - other.0);
+        if !result.0.is_finite() {
+            return Err(TemporalError::range().with_message("number value is not a finite value."));
+        }
+        Ok(result)
+    }
+
+    /// Multiplies this value by `other`, returning an error if the result is not finite.
+    #[inline]
+    pub fn checked_mul(&self, other: &Self) -> TemporalResult<Self> {
+        let result = Self(self.0 * other.0);
+        if !result.0.is_finite() {
+            return Err(TemporalError::range().with_message("number value is not a finite value."));
+        }
+        Ok(result)
+    }
+
     #[inline]
     pub fn checked_mul_add(&self, a: FiniteF64, b: FiniteF64) -> TemporalResult<Self> {
         let result = Self(self.0.mul_add(a.0, b.0));
@@ -73,6 +100,12 @@ impl AsPrimitive<i128> for FiniteF64 {
     }
 }
 
+impl From<FiniteF64> for f64 {
+    fn from(value: FiniteF64) -> Self {
+        value.0
+    }
+}
+
 impl TryFrom<f64> for FiniteF64 {
     type Error = TemporalError;
     fn try_from(value: f64) -> Result<Self, Self::Error> {
@@ -132,7 +165,38 @@ impl PartialEq<f64> for FiniteF64 {
 }
 
 impl PartialOrd<f64> for FiniteF64 {
-    fn partial_cmp(&self, other: &f64) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &f64) -> Option<core::cmp::Ordering> {
         self.0.partial_cmp(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FiniteF64;
+
+    #[test]
+    fn finite_f64_rejects_non_finite_values() {
+        assert!(FiniteF64::try_from(f64::NAN).is_err());
+        assert!(FiniteF64::try_from(f64::INFINITY).is_err());
+        assert!(FiniteF64::try_from(f64::NEG_INFINITY).is_err());
+
+        let value = FiniteF64::try_from(1.5).unwrap();
+        assert_eq!(f64::from(value), 1.5);
+        let inner: f64 = value.into();
+        assert_eq!(inner, 1.5);
+    }
+
+    #[test]
+    fn finite_f64_checked_arithmetic() {
+        let two = FiniteF64::from(2);
+        let three = FiniteF64::from(3);
+        assert_eq!(two.checked_add(&three).unwrap(), 5.0);
+        assert_eq!(two.checked_sub(&three).unwrap(), -1.0);
+        assert_eq!(two.checked_mul(&three).unwrap(), 6.0);
+
+        let max = FiniteF64::try_from(f64::MAX).unwrap();
+        assert!(max.checked_add(&max).is_err());
+        assert!(max.negate().checked_sub(&max).is_err());
+        assert!(max.checked_mul(&two).is_err());
+    }
+}
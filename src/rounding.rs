@@ -5,7 +5,7 @@ use crate::{
     temporal_assert, TemporalError, TemporalResult, TemporalUnwrap,
 };
 
-use std::{
+use core::{
     cmp::Ordering,
     num::NonZeroU128,
     ops::{Div, Neg},
@@ -19,18 +19,18 @@ pub(crate) trait Roundable:
     fn is_exact(dividend: Self, divisor: Self) -> bool;
     fn compare_remainder(dividend: Self, divisor: Self) -> Option<Ordering>;
     fn is_even_cardinal(dividend: Self, divisor: Self) -> bool;
-    fn result_floor(dividend: Self, divisor: Self) -> u64;
-    fn result_ceil(dividend: Self, divisor: Self) -> u64;
+    fn result_floor(dividend: Self, divisor: Self) -> u128;
+    fn result_ceil(dividend: Self, divisor: Self) -> u128;
     fn quotient_abs(dividend: Self, divisor: Self) -> Self {
         // NOTE: Sanity debugs until proper unit tests to vet the below
-        debug_assert!(<i128 as NumCast>::from((dividend / divisor).abs()) < Some(u64::MAX as i128));
+        debug_assert!(<u128 as NumCast>::from((dividend / divisor).abs()).is_some());
         (dividend / divisor).abs()
     }
 }
 
 pub(crate) trait Round {
     fn round(&self, mode: TemporalRoundingMode) -> i128;
-    fn round_as_positive(&self, mode: TemporalRoundingMode) -> u64;
+    fn round_as_positive(&self, mode: TemporalRoundingMode) -> u128;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -85,12 +85,13 @@ impl<T: Roundable> Round for IncrementRounder<T> {
     }
 
     #[inline]
-    fn round_as_positive(&self, mode: TemporalRoundingMode) -> u64 {
+    fn round_as_positive(&self, mode: TemporalRoundingMode) -> u128 {
         let unsigned_rounding_mode = mode.get_unsigned_round_mode(self.sign);
         let rounded =
             apply_unsigned_rounding_mode(self.dividend, self.divisor, unsigned_rounding_mode);
         // TODO: Add unit tests for the below
-        rounded * <u64 as NumCast>::from(self.divisor).expect("increment is representable by a u64")
+        rounded
+            * <u128 as NumCast>::from(self.divisor).expect("increment is representable by a u128")
     }
 }
 
@@ -100,19 +101,21 @@ impl Roundable for i128 {
     }
 
     fn compare_remainder(dividend: Self, divisor: Self) -> Option<Ordering> {
-        Some((dividend.abs() % divisor).cmp(&(divisor / 2)))
+        // NOTE: The remainder is doubled rather than halving the divisor, which would truncate
+        // for odd divisors.
+        Some((2 * (dividend.abs() % divisor)).cmp(&divisor))
     }
 
     fn is_even_cardinal(dividend: Self, divisor: Self) -> bool {
         Roundable::result_floor(dividend, divisor).rem_euclid(2) == 0
     }
 
-    fn result_floor(dividend: Self, divisor: Self) -> u64 {
-        Roundable::quotient_abs(dividend, divisor) as u64
+    fn result_floor(dividend: Self, divisor: Self) -> u128 {
+        Roundable::quotient_abs(dividend, divisor) as u128
     }
 
-    fn result_ceil(dividend: Self, divisor: Self) -> u64 {
-        Roundable::quotient_abs(dividend, divisor) as u64 + 1
+    fn result_ceil(dividend: Self, divisor: Self) -> u128 {
+        Roundable::quotient_abs(dividend, divisor) as u128 + 1
     }
 }
 
@@ -134,12 +137,12 @@ impl Roundable for f64 {
         (quotient.floor() / (quotient.ceil() - quotient.floor()) % 2.0) == 0.0
     }
 
-    fn result_floor(dividend: Self, divisor: Self) -> u64 {
-        Roundable::quotient_abs(dividend, divisor).floor() as u64
+    fn result_floor(dividend: Self, divisor: Self) -> u128 {
+        Roundable::quotient_abs(dividend, divisor).floor() as u128
     }
 
-    fn result_ceil(dividend: Self, divisor: Self) -> u64 {
-        Roundable::quotient_abs(dividend, divisor).ceil() as u64
+    fn result_ceil(dividend: Self, divisor: Self) -> u128 {
+        Roundable::quotient_abs(dividend, divisor).ceil() as u128
     }
 }
 
@@ -148,7 +151,7 @@ fn apply_unsigned_rounding_mode<T: Roundable>(
     dividend: T,
     divisor: T,
     unsigned_rounding_mode: TemporalUnsignedRoundingMode,
-) -> u64 {
+) -> u128 {
     // is_floor
     // 1. If x is equal to r1, return r1.
     if Roundable::is_exact(dividend, divisor) {
@@ -199,7 +202,7 @@ fn apply_unsigned_rounding_mode<T: Roundable>(
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroU128;
+    use core::num::NonZeroU128;
 
     use super::{IncrementRounder, Round, TemporalRoundingMode};
 
@@ -324,4 +327,41 @@ mod tests {
 
         assert_eq!(result, -84083400000000000);
     }
+
+    #[test]
+    fn i128_rounding_odd_divisor_and_trunc() {
+        let result = IncrementRounder::<i128>::from_positive_parts(7, NonZeroU128::new(5).unwrap())
+            .unwrap()
+            .round_as_positive(TemporalRoundingMode::HalfExpand);
+        assert_eq!(result, 5);
+
+        let result = IncrementRounder::<i128>::from_positive_parts(8, NonZeroU128::new(5).unwrap())
+            .unwrap()
+            .round_as_positive(TemporalRoundingMode::HalfTrunc);
+        assert_eq!(result, 10);
+
+        let result = IncrementRounder::<i128>::from_potentially_negative_parts(
+            -15,
+            NonZeroU128::new(10).unwrap(),
+        )
+        .unwrap()
+        .round(TemporalRoundingMode::Trunc);
+        assert_eq!(result, -10);
+
+        let result = IncrementRounder::<i128>::from_potentially_negative_parts(
+            -25,
+            NonZeroU128::new(10).unwrap(),
+        )
+        .unwrap()
+        .round(TemporalRoundingMode::HalfEven);
+        assert_eq!(result, -20);
+
+        let result = IncrementRounder::<i128>::from_potentially_negative_parts(
+            -35,
+            NonZeroU128::new(10).unwrap(),
+        )
+        .unwrap()
+        .round(TemporalRoundingMode::HalfEven);
+        assert_eq!(result, -40);
+    }
 }
@@ -0,0 +1,166 @@
+//! Conversions between UTC `Instant`s and the TAI and GPS timescales.
+//!
+//! Temporal is leap-second-agnostic: an `Instant` counts nanoseconds since the Unix epoch
+//! on the UTC timescale with every day being exactly 86,400 seconds long. TAI and GPS time
+//! instead count every elapsed SI second, so converting to them requires the number of leap
+//! seconds in effect, which the caller provides as a leap second table.
+//!
+//! A leap second table is a slice of `(Instant, i32)` entries sorted by `Instant`, where each
+//! entry is the UTC instant from which the offset `TAI - UTC` in seconds applies, e.g.
+//! `(2017-01-01T00:00:00Z, 37)`. Instants before the first entry use an offset of zero.
+
+use crate::{components::Instant, TemporalError, TemporalResult, NS_PER_SECOND};
+
+/// The GPS epoch, `1980-01-06T00:00:00Z`, in nanoseconds since the Unix epoch.
+pub const GPS_EPOCH_NANOSECONDS: i128 = 315_964_800 * NS_PER_SECOND as i128;
+
+/// The fixed offset `TAI - GPS` in seconds.
+pub const TAI_GPS_OFFSET_SECONDS: i32 = 19;
+
+/// Returns the offset `TAI - UTC` in seconds at the provided UTC `Instant`.
+pub fn tai_utc_offset_seconds(instant: &Instant, leap_table: &[(Instant, i32)]) -> i32 {
+    leap_table
+        .iter()
+        .take_while(|(start, _)| start <= instant)
+        .last()
+        .map_or(0, |(_, offset)| *offset)
+}
+
+impl Instant {
+    /// Creates an `Instant` from TAI nanoseconds since `1970-01-01T00:00:00` using the
+    /// provided leap second table.
+    ///
+    /// A TAI time within an inserted leap second has no UTC representation, and maps to the
+    /// second that follows it.
+    pub fn from_tai_nanoseconds(
+        tai_nanoseconds: i128,
+        leap_table: &[(Instant, i32)],
+    ) -> TemporalResult<Self> {
+        // Compare the table entries on the TAI timescale, where they are strictly increasing.
+        let offset = leap_table
+            .iter()
+            .take_while(|(start, offset)| {
+                start
+                    .epoch_nanos
+                    .checked_add(offset_nanoseconds(*offset))
+                    .is_some_and(|start| start <= tai_nanoseconds)
+            })
+            .last()
+            .map_or(0, |(_, offset)| *offset);
+        let epoch_nanos = tai_nanoseconds
+            .checked_sub(offset_nanoseconds(offset))
+            .ok_or_else(timescale_range_error)?;
+        Self::new(epoch_nanos)
+    }
+
+    /// Returns the TAI nanoseconds since `1970-01-01T00:00:00` for this `Instant` using the
+    /// provided leap second table.
+    #[must_use]
+    pub fn to_tai_nanoseconds(&self, leap_table: &[(Instant, i32)]) -> i128 {
+        self.epoch_nanos + offset_nanoseconds(tai_utc_offset_seconds(self, leap_table))
+    }
+
+    /// Creates an `Instant` from GPS nanoseconds since the GPS epoch using the provided leap
+    /// second table.
+    pub fn from_gps_nanoseconds(
+        gps_nanoseconds: i128,
+        leap_table: &[(Instant, i32)],
+    ) -> TemporalResult<Self> {
+        let tai = gps_nanoseconds
+            .checked_add(GPS_EPOCH_NANOSECONDS + offset_nanoseconds(TAI_GPS_OFFSET_SECONDS))
+            .ok_or_else(timescale_range_error)?;
+        Self::from_tai_nanoseconds(tai, leap_table)
+    }
+
+    /// Returns the GPS nanoseconds since the GPS epoch for this `Instant` using the provided
+    /// leap second table.
+    #[must_use]
+    pub fn to_gps_nanoseconds(&self, leap_table: &[(Instant, i32)]) -> i128 {
+        self.to_tai_nanoseconds(leap_table)
+            - offset_nanoseconds(TAI_GPS_OFFSET_SECONDS)
+            - GPS_EPOCH_NANOSECONDS
+    }
+}
+
+/// Returns an offset in seconds as nanoseconds.
+fn offset_nanoseconds(offset_seconds: i32) -> i128 {
+    i128::from(offset_seconds) * i128::from(NS_PER_SECOND)
+}
+
+fn timescale_range_error() -> TemporalError {
+    TemporalError::range().with_message("Timescale nanoseconds are not within a valid range.")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{components::Instant, error::ErrorKind};
+
+    use super::{tai_utc_offset_seconds, GPS_EPOCH_NANOSECONDS};
+
+    const NS_PER_SECOND: i128 = crate::NS_PER_SECOND as i128;
+
+    fn leap_table() -> [(Instant, i32); 4] {
+        [
+            // 1972-01-01T00:00:00Z
+            (Instant::new(63_072_000 * NS_PER_SECOND).unwrap(), 10),
+            // 1980-01-01T00:00:00Z
+            (Instant::new(315_532_800 * NS_PER_SECOND).unwrap(), 19),
+            // 2015-07-01T00:00:00Z
+            (Instant::new(1_435_708_800 * NS_PER_SECOND).unwrap(), 36),
+            // 2017-01-01T00:00:00Z
+            (Instant::new(1_483_228_800 * NS_PER_SECOND).unwrap(), 37),
+        ]
+    }
+
+    #[test]
+    fn gps_offset() {
+        let table = leap_table();
+
+        let gps_epoch = Instant::new(GPS_EPOCH_NANOSECONDS).unwrap();
+        assert_eq!(gps_epoch.to_gps_nanoseconds(&table), 0);
+        assert_eq!(Instant::from_gps_nanoseconds(0, &table).unwrap(), gps_epoch);
+
+        // 2024-01-01T00:00:00Z, when GPS time is 18 seconds ahead of UTC.
+        let instant = Instant::new(1_704_067_200 * NS_PER_SECOND).unwrap();
+        assert_eq!(tai_utc_offset_seconds(&instant, &table), 37);
+        let gps = instant.to_gps_nanoseconds(&table);
+        assert_eq!(
+            gps - (instant.epoch_nanos - GPS_EPOCH_NANOSECONDS),
+            18 * NS_PER_SECOND
+        );
+        assert_eq!(Instant::from_gps_nanoseconds(gps, &table).unwrap(), instant);
+    }
+
+    #[test]
+    fn tai_round_trip_across_leap_second() {
+        let table = leap_table();
+        let start = table[3].0.epoch_nanos;
+
+        let before = Instant::new(start - NS_PER_SECOND).unwrap();
+        let tai = before.to_tai_nanoseconds(&table);
+        assert_eq!(tai, start + 35 * NS_PER_SECOND);
+        assert_eq!(Instant::from_tai_nanoseconds(tai, &table).unwrap(), before);
+
+        let after = Instant::new(start).unwrap();
+        let tai = after.to_tai_nanoseconds(&table);
+        assert_eq!(tai, start + 37 * NS_PER_SECOND);
+        assert_eq!(Instant::from_tai_nanoseconds(tai, &table).unwrap(), after);
+
+        // The inserted leap second maps to the following second.
+        let leap = Instant::from_tai_nanoseconds(start + 36 * NS_PER_SECOND, &table).unwrap();
+        assert_eq!(leap, after);
+
+        // Before the first table entry, TAI and UTC are the same.
+        let epoch = Instant::new(0).unwrap();
+        assert_eq!(epoch.to_tai_nanoseconds(&table), 0);
+    }
+
+    #[test]
+    fn out_of_range_nanoseconds() {
+        let table = leap_table();
+        let err = Instant::from_gps_nanoseconds(i128::MAX, &table).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        let err = Instant::from_tai_nanoseconds(i128::MIN, &table).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+    }
+}
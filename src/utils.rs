@@ -1,6 +1,6 @@
 //! Utility date and time equations for Temporal
 
-use crate::MS_PER_DAY;
+use crate::{options::Precision, TemporalError, TemporalResult, MS_PER_DAY};
 
 // NOTE: Review the below for optimizations and add ALOT of tests.
 
@@ -16,11 +16,21 @@ pub(crate) fn epoch_days_to_epoch_ms(day: i32, time: f64) -> f64 {
     f64::from(day).mul_add(f64::from(MS_PER_DAY), time).floor()
 }
 
+/// Validates that a UTC offset in minutes is less than a day in either direction, i.e. within
+/// `-1439..=1439`, and returns the offset in nanoseconds.
+pub(crate) fn validate_offset_nanoseconds(offset_minutes: i32) -> TemporalResult<i128> {
+    if offset_minutes.abs() >= 1440 {
+        return Err(TemporalError::range()
+            .with_message("Offset must be less than a day in either direction."));
+    }
+    Ok(i128::from(offset_minutes) * 60_000_000_000)
+}
+
 /// 3.5.11 PadISOYear ( y )
 ///
 /// returns a String representation of y suitable for inclusion in an ISO 8601 string
 pub(crate) fn pad_iso_year(year: i32) -> String {
-    if (0..9999).contains(&year) {
+    if (0..=9999).contains(&year) {
         return format!("{:04}", year);
     }
     let year_sign = if year > 0 { "+" } else { "-" };
@@ -28,6 +38,45 @@ pub(crate) fn pad_iso_year(year: i32) -> String {
     format!("{year_sign}{year_string}",)
 }
 
+/// Formats the sub-second nanoseconds of a time with the provided precision, including the
+/// decimal point, e.g. `.120` for `Precision::Digit(3)`.
+///
+/// Returns an empty string when there are no fractional digits to display. `Precision::Auto`
+/// trims every trailing zero, e.g. `.12` rather than `.120`, as the specification requires.
+///
+/// Equivalent to `FormatFractionalSeconds ( subSecondNanoseconds, precision )`
+pub(crate) fn format_fractional_seconds(
+    sub_second_nanoseconds: u32,
+    precision: Precision,
+) -> String {
+    let digits = match precision {
+        // 1. If precision is auto, then
+        Precision::Auto => {
+            // a. If subSecondNanoseconds = 0, return the empty String.
+            if sub_second_nanoseconds == 0 {
+                return String::new();
+            }
+            // b. Let fractionString be ToZeroPaddedDecimalString(subSecondNanoseconds, 9).
+            // c. Set fractionString to the longest prefix of fractionString ending with a code
+            // unit other than 0x0030 (DIGIT ZERO).
+            let fraction = format!("{sub_second_nanoseconds:09}");
+            return format!(".{}", fraction.trim_end_matches('0'));
+        }
+        Precision::Minute => return String::new(),
+        Precision::Digit(digits) => usize::from(digits.min(9)),
+    };
+    // 2. Else,
+    // a. If precision = 0, return the empty String.
+    if digits == 0 {
+        return String::new();
+    }
+    // b. Let fractionString be ToZeroPaddedDecimalString(subSecondNanoseconds, 9).
+    // c. Set fractionString to the substring of fractionString from 0 to precision.
+    let fraction = format!("{sub_second_nanoseconds:09}");
+    // 3. Return the string-concatenation of the code unit 0x002E (FULL STOP) and fractionString.
+    format!(".{}", &fraction[..digits])
+}
+
 /// `EpochTimeToDayNumber`
 ///
 /// This equation is the equivalent to `ECMAScript`'s `Date(t)`